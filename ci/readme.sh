@@ -0,0 +1,33 @@
+# This script regenerates the resource list of README.md with `hrkk resources`.
+# With --check, it fails when README.md is out of date instead.
+# HRKK overrides the command to run, e.g. HRKK=target/debug/hrkk.
+
+set -e
+
+main() {
+    local start='<!-- resources: generated by ci/readme.sh -->' \
+          end='<!-- end of resources -->' \
+          table=$(mktemp) \
+          readme=$(mktemp)
+
+    ${HRKK:-cargo run --quiet --} resources --output markdown > $table
+
+    awk -v start="$start" -v end="$end" -v table="$table" '
+        $0 == start { print; while ((getline line < table) > 0) print line; skip = 1; next }
+        $0 == end { skip = 0 }
+        !skip { print }
+    ' README.md > $readme
+
+    if [ "$1" = "--check" ]; then
+        diff -u README.md $readme || {
+            echo "README.md is out of date, run: bash ci/readme.sh" >&2
+            exit 1
+        }
+    else
+        cp $readme README.md
+    fi
+
+    rm -f $table $readme
+}
+
+main "$@"
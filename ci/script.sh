@@ -14,6 +14,8 @@ main() {
     cross test --target $TARGET
     cross test --target $TARGET --release
 
+    bash ci/readme.sh --check
+
     cross run --target $TARGET
     cross run --target $TARGET --release
 }
@@ -0,0 +1,76 @@
+use crate::error::AwsApiError;
+use rusoto_core::request::BufferedHttpResponse;
+use serde_json::Value;
+use xml::reader::XmlEvent;
+use xml::EventReader;
+
+/// Parse `<Error><Code><Message>` of query, ec2 and rest-xml apis or `__type`/`message` of json apis.
+pub(crate) fn parse(response: &BufferedHttpResponse) -> AwsApiError {
+    let body = response.body.as_ref();
+    let (code, message, request_id) = match serde_json::from_slice(body) {
+        Ok(Value::Object(map)) => {
+            let string = |keys: &[&str]| {
+                keys.iter()
+                    .filter_map(|key| map.get(*key).and_then(|v| v.as_str()))
+                    .next()
+                    .map(|s| s.to_string())
+            };
+            (
+                string(&["__type", "code", "Code"]),
+                string(&["message", "Message", "errorMessage"]),
+                None,
+            )
+        }
+        _ => from_xml(body),
+    };
+
+    let header = |name: &str| response.headers.get(name).map(|v| v.to_string());
+
+    AwsApiError {
+        code: code
+            .or_else(|| header("x-amzn-errortype"))
+            .map(|code| trim_code(&code))
+            .unwrap_or_else(|| {
+                response
+                    .status
+                    .canonical_reason()
+                    .unwrap_or("UnknownError")
+                    .replace(" ", "")
+            }),
+        message: message
+            .unwrap_or_else(|| String::from_utf8(body.to_vec()).unwrap_or_else(|_| "".to_string())),
+        request_id: request_id
+            .or_else(|| header("x-amzn-requestid"))
+            .or_else(|| header("x-amz-request-id")),
+        status: response.status.as_u16(),
+        retry_count: 0,
+    }
+}
+
+fn from_xml(body: &[u8]) -> (Option<String>, Option<String>, Option<String>) {
+    let (mut code, mut message, mut request_id) = (None, None, None);
+    let mut tag = String::new();
+
+    for event in EventReader::new(body) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => tag = name.local_name,
+            Ok(XmlEvent::Characters(text)) => match &tag[..] {
+                "Code" if code.is_none() => code = Some(text),
+                "Message" if message.is_none() => message = Some(text),
+                "RequestId" | "RequestID" if request_id.is_none() => request_id = Some(text),
+                _ => (),
+            },
+            Ok(XmlEvent::EndElement { .. }) => tag.clear(),
+            Ok(_) => (),
+            Err(_) => break,
+        }
+    }
+
+    (code, message, request_id)
+}
+
+/// "com.amazonaws.logs#ResourceNotFoundException" or "AccessDeniedException:http://..." to the bare code.
+fn trim_code(code: &str) -> String {
+    let code = code.rsplit('#').next().unwrap_or(code);
+    code.split(':').next().unwrap_or(code).to_string()
+}
@@ -0,0 +1,199 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use crate::opts::Opts;
+use crate::service::AwsResource;
+use chrono::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+use yaml_rust::{Yaml, YamlEmitter, YamlLoader};
+
+/// Seconds to keep cached responses for --offline even with a shorter --cache-ttl.
+const MIN_RETENTION: u64 = 60 * 60 * 24;
+
+/// Converted api response stored under
+/// `<cache dir>/hrkk/<profile>/<region>/<resource name>/<api>-<hash>.yaml`,
+/// the profile is followed by the hash of the role arn when it assumes a role.
+pub(crate) struct Key {
+    path: PathBuf,
+    description: String,
+}
+
+impl Key {
+    pub(crate) fn new(
+        opts: &Opts,
+        resource: &dyn AwsResource,
+        api: &str,
+        parameter: &Option<String>,
+        next_token: &Option<String>,
+    ) -> Self {
+        let identity = format!(
+            "{:?}/{:?}/{:?}",
+            parameter,
+            next_token,
+            opts.endpoint_url(&resource.service_name())
+        );
+
+        let mut path = resource_dir(opts, &resource.name());
+        path.push(format!("{}-{}.yaml", api, hash(&identity)));
+
+        Key {
+            path,
+            description: format!("{} {} {:?}", resource.name(), api, parameter),
+        }
+    }
+}
+
+pub(crate) fn resource_dir(opts: &Opts, resource_name: &str) -> PathBuf {
+    let mut path = dirs::cache_dir().unwrap_or_else(std::env::temp_dir);
+    path.push("hrkk");
+    path.push(match super::credentials::role_arn(opts) {
+        Some(arn) => format!("{}-{}", opts.profile_name(), hash(&arn)),
+        None => opts.profile_name(),
+    });
+    path.push(opts.region_name());
+    path.push(resource_name);
+    path
+}
+
+/// Cached yaml and its stored time, if the cache is fresh enough or hrkk is offline.
+pub(crate) fn load(
+    key: &Key,
+    opts: &Opts,
+    use_cache: bool,
+) -> Result<Option<(Yaml, DateTime<Local>)>> {
+    if opts.offline {
+        return match read(key) {
+            Some(cached) => Ok(Some(cached)),
+            None => Err(CacheMissError(key.description.clone())),
+        };
+    }
+
+    let ttl = match opts.cache_ttl {
+        Some(ttl) if use_cache && ttl > 0 => ttl as i64,
+        _ => return Ok(None),
+    };
+
+    Ok(match read(key) {
+        Some((yaml, time)) if Local::now().signed_duration_since(time).num_seconds() < ttl => {
+            Some((yaml, time))
+        }
+        _ => None,
+    })
+}
+
+pub(crate) fn read(key: &Key) -> Option<(Yaml, DateTime<Local>)> {
+    let modified = fs::metadata(&key.path).ok()?.modified().ok()?;
+    let text = fs::read_to_string(&key.path).ok()?;
+    let yaml = YamlLoader::load_from_str(&text).ok()?.into_iter().next()?;
+    Some((yaml, modified.into()))
+}
+
+/// Cached list yamls of the resource, all pages and parameters in no particular order.
+pub(crate) fn lists(opts: &Opts, resource_name: &str) -> Vec<Yaml> {
+    let entries = match fs::read_dir(resource_dir(opts, resource_name)) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            matches!(path.file_name().and_then(|name| name.to_str()),
+                Some(name) if name.starts_with("list-"))
+        })
+        .filter_map(|path| {
+            read(&Key {
+                path,
+                description: String::new(),
+            })
+        })
+        .map(|(yaml, _)| yaml)
+        .collect()
+}
+
+/// Responses are stored only with --cache-ttl or --offline, they may have secrets
+/// like environment variables of lambda functions.
+fn is_enabled(opts: &Opts) -> bool {
+    opts.offline || matches!(opts.cache_ttl, Some(ttl) if ttl > 0)
+}
+
+/// Caching is best effort, a failure to write doesn't fail the api call.
+pub(crate) fn store(key: &Key, yaml: &Yaml, opts: &Opts) {
+    if !is_enabled(opts) {
+        return;
+    }
+
+    let mut out_str = String::new();
+    if YamlEmitter::new(&mut out_str).dump(yaml).is_err() {
+        return;
+    }
+
+    if let Some(dir) = key.path.parent() {
+        let _ = fs::create_dir_all(dir);
+        remove_stale(dir, opts);
+    }
+    let _ = write_private(&key.path, &out_str);
+}
+
+/// Remove entries older than the ttl, but keep a day of them for --offline.
+fn remove_stale(dir: &std::path::Path, opts: &Opts) {
+    let max_age = std::cmp::max(opts.cache_ttl.unwrap_or(0), MIN_RETENTION);
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let age = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok());
+        if let Some(age) = age {
+            if age.as_secs() > max_age {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+pub(crate) fn age(time: &DateTime<Local>) -> String {
+    let seconds = Local::now().signed_duration_since(*time).num_seconds();
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 60 * 60 {
+        format!("{}m", seconds / 60)
+    } else if seconds < 60 * 60 * 24 {
+        format!("{}h", seconds / (60 * 60))
+    } else {
+        format!("{}d", seconds / (60 * 60 * 24))
+    }
+}
+
+/// FNV-1a, stable across builds unlike `DefaultHasher`.
+pub(crate) fn hash(text: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Files readable only by the user, responses and session credentials may have secrets.
+#[cfg(unix)]
+pub(crate) fn write_private(path: &PathBuf, text: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+    fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?
+        .write_all(text.as_bytes())
+}
+
+#[cfg(not(unix))]
+pub(crate) fn write_private(path: &PathBuf, text: &str) -> std::io::Result<()> {
+    fs::write(path, text)
+}
@@ -0,0 +1,300 @@
+use super::{cache, profile};
+use crate::error::Error::*;
+use crate::error::Result;
+use crate::opts::Opts;
+use chrono::prelude::*;
+use futures::future::BoxFuture;
+use rusoto_core::param::{Params, ServiceParams};
+use rusoto_core::signature::SignedRequest;
+use rusoto_credential::AwsCredentials;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Credentials shared by all requests, resolved once per profile and role.
+/// Each profile and role has its own lock, so one role assumption doesn't wait for another.
+#[derive(Clone, Default)]
+pub(crate) struct Store(Arc<std::sync::Mutex<HashMap<String, Slot>>>);
+
+type Slot = Arc<Mutex<Option<AwsCredentials>>>;
+
+impl Store {
+    fn slot(&self, key: &str) -> Slot {
+        let mut slots = self.0.lock().unwrap();
+        slots.entry(key.to_string()).or_default().clone()
+    }
+}
+
+impl std::fmt::Debug for Store {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Store")
+    }
+}
+
+/// Role to assume, from --role-arn or `role_arn` of the profile in ~/.aws/config.
+#[derive(Debug, Clone)]
+struct Role {
+    arn: String,
+    source_profile: String,
+    mfa_serial: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Session {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    expiration: String,
+}
+
+pub(crate) async fn get(opts: &Opts) -> Result<AwsCredentials> {
+    resolve(opts, &opts.profile_name(), role(opts), None, &[]).await
+}
+
+/// Serial of the mfa device to prompt the token code for, if there is no valid session of the role.
+/// Replayed and offline runs send no requests, so they need no credentials.
+pub(crate) fn mfa_serial_to_prompt(opts: &Opts) -> Option<String> {
+    if opts.replay.is_some() || opts.offline {
+        return None;
+    }
+    let role = role(opts)?;
+    let serial = role.mfa_serial.clone()?;
+    match load_session(&role) {
+        Some(_) => None,
+        None => Some(serial),
+    }
+}
+
+pub(crate) async fn assume_role_with_token(opts: &Opts, token_code: &str) -> Result<()> {
+    resolve(
+        opts,
+        &opts.profile_name(),
+        role(opts),
+        Some(token_code),
+        &[],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Arn of the role the requests of the profile are sent as.
+pub(crate) fn role_arn(opts: &Opts) -> Option<String> {
+    role(opts).map(|role| role.arn)
+}
+
+fn role(opts: &Opts) -> Option<Role> {
+    let profile = opts.profile_name();
+    match &opts.role_arn {
+        Some(arn) => Some(Role {
+            arn: arn.clone(),
+            source_profile: profile,
+            mfa_serial: opts.mfa_serial.clone(),
+        }),
+        None => profile_role(&profile),
+    }
+}
+
+fn profile_role(profile: &str) -> Option<Role> {
+    let properties = profile::properties(profile);
+    Some(Role {
+        arn: properties.get("role_arn")?.clone(),
+        source_profile: properties
+            .get("source_profile")
+            .cloned()
+            .unwrap_or_else(|| profile.to_string()),
+        mfa_serial: properties.get("mfa_serial").cloned(),
+    })
+}
+
+/// Credentials of the profile or the role, while the key is locked not to assume the same role twice.
+/// `sources` are the profiles whose roles are assumed with these credentials, to stop at source_profile loops.
+fn resolve<'a>(
+    opts: &'a Opts,
+    profile: &'a str,
+    role: Option<Role>,
+    token_code: Option<&'a str>,
+    sources: &'a [String],
+) -> BoxFuture<'a, Result<AwsCredentials>> {
+    Box::pin(async move {
+        let key = match &role {
+            Some(role) => format!("{}/{}", profile, role.arn),
+            None => profile.to_string(),
+        };
+
+        let slot = opts.credentials.slot(&key);
+        let mut stored = slot.lock().await;
+        if let Some(credentials) = stored.as_ref() {
+            if !expires_soon(credentials.expires_at()) {
+                return Ok(credentials.clone());
+            }
+        }
+
+        let credentials = match role {
+            None => {
+                let named = opts.is_profile_named() || profile != opts.profile_name();
+                profile::credentials(profile, named).await?
+            }
+            Some(role) => match load_session(&role) {
+                Some(credentials) => credentials,
+                None => {
+                    let mut sources = sources.to_vec();
+                    sources.push(profile.to_string());
+                    let source_role = match role.source_profile.as_str() {
+                        source if source == profile => None,
+                        source if sources.iter().any(|s| s == source) => {
+                            sources.push(source.to_string());
+                            return Err(SettingError(format!(
+                                "source_profile loop: {}",
+                                sources.join(" -> ")
+                            )));
+                        }
+                        source => profile_role(source),
+                    };
+                    let source =
+                        resolve(opts, &role.source_profile, source_role, None, &sources).await?;
+                    let credentials = assume_role(opts, &role, &source, token_code).await?;
+                    store_session(&role, &credentials);
+                    credentials
+                }
+            },
+        };
+
+        *stored = Some(credentials.clone());
+        Ok(credentials)
+    })
+}
+
+async fn assume_role(
+    opts: &Opts,
+    role: &Role,
+    source: &AwsCredentials,
+    token_code: Option<&str>,
+) -> Result<AwsCredentials> {
+    // the regional endpoint works in the china and gov cloud partitions too.
+    let region = opts.request_region("sts", &Some(opts.partition_region()?))?;
+    let mut params = Params::new();
+
+    params.put("Action", "AssumeRole");
+    params.put("Version", "2011-06-15");
+    params.put("RoleArn", &role.arn);
+    params.put(
+        "RoleSessionName",
+        format!("hrkk-{}", Utc::now().timestamp()),
+    );
+    if let Some(serial) = &role.mfa_serial {
+        match token_code {
+            Some(token_code) => {
+                params.put("SerialNumber", serial);
+                params.put("TokenCode", token_code);
+            }
+            None => {
+                return Err(ArgumentError(format!(
+                    "mfa token code is needed for {}, restart hrkk to enter it.",
+                    serial
+                )))
+            }
+        }
+    }
+
+    let payload = serde_urlencoded::to_string(&params)?;
+    // a token code is accepted only once, so a retry after a lost response would be denied.
+    let max_retries = match token_code {
+        Some(_) => 0,
+        None => opts.max_retries(),
+    };
+    let (response, _) = super::send_signed(
+        || {
+            let mut request = SignedRequest::new("POST", "sts", &region, "/");
+            request.set_payload(Some(payload.clone()));
+            request.set_content_type("application/x-www-form-urlencoded".to_owned());
+            Ok(request)
+        },
+        opts,
+        source,
+        max_retries,
+    )
+    .await?;
+
+    let yaml = super::xml_to_yaml::convert(response.body.as_ref(), &vec![])?;
+    let credentials = &yaml["assume_role_result"]["credentials"];
+    let field = |name: &str| match &credentials[name] {
+        yaml_rust::Yaml::String(value) => Ok(value.to_string()),
+        _ => Err(RusotoError(format!(
+            "{} is not in AssumeRole response.",
+            name
+        ))),
+    };
+
+    Ok(AwsCredentials::new(
+        field("access_key_id")?,
+        field("secret_access_key")?,
+        Some(field("session_token")?),
+        DateTime::parse_from_rfc3339(&field("expiration")?)
+            .ok()
+            .map(|time| time.with_timezone(&Utc)),
+    ))
+}
+
+fn expires_soon(expires_at: &Option<DateTime<Utc>>) -> bool {
+    match expires_at {
+        Some(time) => *time < Utc::now() + chrono::Duration::minutes(5),
+        None => false,
+    }
+}
+
+/// Session credentials of a role are cached in the cache dir until they expire.
+fn session_path(role: &Role) -> PathBuf {
+    let mut path = dirs::cache_dir().unwrap_or_else(std::env::temp_dir);
+    path.push("hrkk");
+    path.push("sessions");
+    path.push(format!(
+        "{}.json",
+        cache::hash(&format!(
+            "{}/{}/{:?}",
+            role.source_profile, role.arn, role.mfa_serial
+        ))
+    ));
+    path
+}
+
+fn load_session(role: &Role) -> Option<AwsCredentials> {
+    let text = fs::read_to_string(session_path(role)).ok()?;
+    let session: Session = serde_json::from_str(&text).ok()?;
+    let expiration = DateTime::parse_from_rfc3339(&session.expiration)
+        .ok()?
+        .with_timezone(&Utc);
+    if expires_soon(&Some(expiration)) {
+        return None;
+    }
+
+    Some(AwsCredentials::new(
+        session.access_key_id,
+        session.secret_access_key,
+        Some(session.session_token),
+        Some(expiration),
+    ))
+}
+
+/// Caching is best effort like api responses.
+fn store_session(role: &Role, credentials: &AwsCredentials) {
+    let session = Session {
+        access_key_id: credentials.aws_access_key_id().to_string(),
+        secret_access_key: credentials.aws_secret_access_key().to_string(),
+        session_token: credentials.token().clone().unwrap_or_default(),
+        expiration: match credentials.expires_at() {
+            Some(time) => time.to_rfc3339(),
+            None => return,
+        },
+    };
+
+    let path = session_path(role);
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(text) = serde_json::to_string(&session) {
+        let _ = cache::write_private(&path, &text);
+    }
+}
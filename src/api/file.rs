@@ -15,9 +15,14 @@ pub(crate) fn store_response(body: &[u8]) -> Result<()> {
 }
 
 pub(crate) fn store_yaml(yaml: &Yaml, file_name: &str) -> Result<()> {
+    store_yaml_with_header(yaml, file_name, "")
+}
+
+/// Store yaml after the header, which is comment lines.
+pub(crate) fn store_yaml_with_header(yaml: &Yaml, file_name: &str, header: &str) -> Result<()> {
     let mut file = fs::File::create(format!("{}.yaml", file_name))
         .or(Err(UnableToWriteFileError(file_name.to_string())))?;
-    let mut out_str = String::new();
+    let mut out_str = header.to_string();
     {
         let mut emitter = yaml_rust::YamlEmitter::new(&mut out_str);
         emitter.dump(yaml).unwrap();
@@ -21,7 +21,7 @@ pub(crate) fn request(opts: &Opts, parameter: &str, json_api: &GetJson) -> Resul
     let mut request = SignedRequest::new(
         json_api.method.to_str(),
         json_api.service_name,
-        &opts.region()?,
+        &opts.request_region(json_api.service_name, &None)?,
         &path,
     );
 
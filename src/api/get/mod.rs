@@ -1,34 +1,56 @@
 pub(crate) mod json_helper;
+pub(crate) mod rest_helper;
 pub(crate) mod xml_helper;
 
+use crate::api::{cache, CallStats};
 use crate::error::Result;
 use crate::opts::Opts;
 use crate::service::prelude::Yaml;
-use crate::service::GetFormat;
 use crate::service::{AwsResource, GetApi};
-use crate::yaml_path::apply_path;
+use crate::service::{GetFormat, GetRest};
 use rusoto_core::signature::SignedRequest;
 
 pub(crate) async fn call(
     resource: &dyn AwsResource,
     list_yaml: &Yaml,
     opts: &Opts,
-) -> Result<Yaml> {
-    let response = super::send_request(request(resource, list_yaml, opts)?, opts).await?;
+    use_cache: bool,
+) -> Result<(Yaml, CallStats)> {
+    let parameter = resource
+        .info()
+        .get_api
+        .as_ref()
+        .map(|api| crate::show::raw(&api.param_path.apply(list_yaml)));
+    let key = cache::Key::new(opts, resource, "get", &parameter, &None);
 
-    let yaml = match resource.get_api() {
-        Some(GetFormat::Xml { .. }) => {
-            super::xml_to_yaml::convert(response.body.as_ref(), &vec![])?
+    let (yaml, stats) = match cache::load(&key, opts, use_cache)? {
+        Some((yaml, cached_at)) => (yaml, CallStats::cached(cached_at)),
+        None => {
+            let (response, stats) =
+                super::send_request(|| request(resource, list_yaml, opts), opts).await?;
+
+            let yaml = match resource.get_api() {
+                Some(GetFormat::Xml { .. }) => {
+                    super::xml_to_yaml::convert(response.body.as_ref(), &vec![])?
+                }
+                Some(GetFormat::RestXml(GetRest { iteration_tag, .. })) => {
+                    super::xml_to_yaml::convert(response.body.as_ref(), &iteration_tag)?
+                }
+                Some(GetFormat::Json { .. }) | Some(GetFormat::RestJson { .. }) => {
+                    super::json_to_yaml::convert(response.body.as_ref())?
+                }
+                _ => Yaml::BadValue,
+            };
+            cache::store(&key, &yaml, opts);
+            (yaml, stats)
         }
-        Some(GetFormat::Json { .. }) => super::json_to_yaml::convert(response.body.as_ref())?,
-        _ => Yaml::BadValue,
     };
 
     if opts.debug {
         crate::api::file::store_yaml(&yaml, "get")?;
     }
 
-    Ok(yaml.clone())
+    Ok((yaml, stats))
 }
 
 fn request(resource: &dyn AwsResource, list_yaml: &Yaml, opts: &Opts) -> Result<SignedRequest> {
@@ -39,7 +61,7 @@ fn request(resource: &dyn AwsResource, list_yaml: &Yaml, opts: &Opts) -> Result<
             ..
         }) => xml_helper::request(
             opts,
-            &crate::show::raw(apply_path(list_yaml, param_path)),
+            &crate::show::raw(&param_path.apply(list_yaml)),
             xml_api,
         ),
         Some(GetApi {
@@ -48,9 +70,29 @@ fn request(resource: &dyn AwsResource, list_yaml: &Yaml, opts: &Opts) -> Result<
             ..
         }) => json_helper::request(
             opts,
-            &crate::show::raw(apply_path(list_yaml, param_path)),
+            &crate::show::raw(&param_path.apply(list_yaml)),
             json_api,
         ),
+        Some(GetApi {
+            param_path,
+            format: GetFormat::RestJson(rest_api),
+            ..
+        }) => rest_helper::request(
+            opts,
+            &crate::show::raw(&param_path.apply(list_yaml)),
+            rest_api,
+            true,
+        ),
+        Some(GetApi {
+            param_path,
+            format: GetFormat::RestXml(rest_api),
+            ..
+        }) => rest_helper::request(
+            opts,
+            &crate::show::raw(&param_path.apply(list_yaml)),
+            rest_api,
+            false,
+        ),
         _ => panic!("unknown request type"),
     }
 }
@@ -0,0 +1,26 @@
+use crate::service::prelude::*;
+use serde_json::Value;
+
+pub(crate) fn request(
+    opts: &Opts,
+    parameter: &str,
+    rest_api: &GetRest,
+    json: bool,
+) -> Result<SignedRequest> {
+    let mut bindings = rest_api
+        .params
+        .iter()
+        .map(|(location, value)| (location, Value::from(*value)))
+        .collect::<Vec<_>>();
+    bindings.push((&rest_api.parameter, Value::String(parameter.to_owned())));
+
+    crate::api::rest::request(
+        opts,
+        &rest_api.method,
+        rest_api.service_name,
+        rest_api.path,
+        &rest_api.region,
+        json,
+        bindings,
+    )
+}
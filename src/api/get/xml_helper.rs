@@ -2,7 +2,8 @@ use crate::service::prelude::*;
 use rusoto_core::param::{Params, ServiceParams};
 
 pub(crate) fn request(opts: &Opts, parameter: &str, xml_api: &GetXml) -> Result<SignedRequest> {
-    let mut request = SignedRequest::new("POST", xml_api.service_name, &opts.region()?, "/");
+    let region = opts.request_region(xml_api.service_name, &None)?;
+    let mut request = SignedRequest::new("POST", xml_api.service_name, &region, "/");
     let mut params = Params::new();
 
     params.put("Action", xml_api.action);
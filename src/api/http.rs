@@ -0,0 +1,332 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use crate::opts::Opts;
+use futures::future::BoxFuture;
+use hyper::client::connect::{Connected, Connection};
+use hyper::client::HttpConnector;
+use hyper::service::Service;
+use hyper::Uri;
+use hyper_tls::{HttpsConnector, MaybeHttpsStream};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{HttpClient, Region};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_tls::TlsConnector;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+pub(crate) type Client = Arc<HttpClient<Connector>>;
+
+/// Http clients shared by all requests, one per profile and region to reuse connections.
+#[derive(Clone, Default)]
+pub(crate) struct Pool(Arc<Mutex<HashMap<String, Client>>>);
+
+impl std::fmt::Debug for Pool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Pool")
+    }
+}
+
+impl Pool {
+    pub(crate) fn client(&self, opts: &Opts, region: &Region) -> Result<Client> {
+        let key = format!("{}/{}", opts.profile_name(), region.name());
+        let mut clients = self.0.lock().unwrap();
+        if let Some(client) = clients.get(&key) {
+            return Ok(client.clone());
+        }
+
+        let client = Arc::new(HttpClient::from_connector(Connector::new(opts)?));
+        clients.insert(key, client.clone());
+        Ok(client)
+    }
+}
+
+tokio::task_local! {
+    static CONNECT: Cell<Option<Duration>>;
+}
+
+/// Output of the request and the time to connect, None when a pooled connection is reused.
+pub(crate) async fn with_connect_time<F: Future>(request: F) -> (F::Output, Option<Duration>) {
+    CONNECT
+        .scope(Cell::new(None), async move {
+            let output = request.await;
+            (output, CONNECT.with(Cell::get))
+        })
+        .await
+}
+
+/// Https connector through the proxy of the environment variables, which records the time to connect to the requesting task.
+#[derive(Clone)]
+pub(crate) struct Connector {
+    https: HttpsConnector<HttpConnector>,
+    tls: TlsConnector,
+    proxy: Proxy,
+}
+
+impl Connector {
+    fn new(opts: &Opts) -> Result<Self> {
+        let tls = TlsConnector::from(tls_connector(opts.ca_bundle())?);
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+
+        Ok(Connector {
+            https: HttpsConnector::from((http, tls.clone())),
+            tls,
+            proxy: Proxy::from_env()?,
+        })
+    }
+}
+
+/// Connection to the host, or to the proxy for plain http requests which hyper sends in absolute-form.
+pub(crate) struct Stream {
+    inner: MaybeHttpsStream<TcpStream>,
+    proxy: bool,
+}
+
+impl Connection for Stream {
+    fn connected(&self) -> Connected {
+        self.inner.connected().proxy(self.proxy)
+    }
+}
+
+impl AsyncRead for Stream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for Stream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+impl Service<Uri> for Connector {
+    type Response = Stream;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, std::result::Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), Self::Error>> {
+        self.https.poll_ready(cx)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let start = Instant::now();
+        let connecting: Self::Future = match self.proxy.for_uri(&uri) {
+            Some(proxy) if uri.scheme_str() == Some("https") => {
+                Box::pin(tunnel(proxy, uri, self.tls.clone()))
+            }
+            Some(proxy) => Box::pin(async move {
+                Ok(Stream {
+                    inner: MaybeHttpsStream::Http(connect_proxy(&proxy).await?),
+                    proxy: true,
+                })
+            }),
+            None => {
+                let connecting = self.https.call(uri);
+                Box::pin(async move {
+                    Ok(Stream {
+                        inner: connecting.await?,
+                        proxy: false,
+                    })
+                })
+            }
+        };
+        Box::pin(async move {
+            let stream = connecting.await?;
+            let _ = CONNECT.try_with(|connect| connect.set(Some(start.elapsed())));
+            Ok(stream)
+        })
+    }
+}
+
+/// Tls connector trusting the certificates of the ca bundle in addition to the system ones.
+fn tls_connector(ca_bundle: Option<String>) -> Result<native_tls::TlsConnector> {
+    let mut builder = native_tls::TlsConnector::builder();
+
+    if let Some(path) = ca_bundle {
+        let pem = std::fs::read_to_string(&path)
+            .map_err(|e| TlsError(format!("unable to read ca bundle {}: {}", path, e)))?;
+        let certificates = pem
+            .split_inclusive("-----END CERTIFICATE-----")
+            .filter(|block| block.contains("-----BEGIN CERTIFICATE-----"))
+            .collect::<Vec<&str>>();
+        if certificates.is_empty() {
+            return Err(TlsError(format!(
+                "no pem certificate in ca bundle {}.",
+                path
+            )));
+        }
+
+        for certificate in certificates {
+            builder.add_root_certificate(
+                native_tls::Certificate::from_pem(certificate.trim().as_bytes()).map_err(|e| {
+                    TlsError(format!("invalid certificate in ca bundle {}: {}", path, e))
+                })?,
+            );
+        }
+    }
+
+    builder
+        .build()
+        .map_err(|e| TlsError(format!("unable to create tls connector: {}", e)))
+}
+
+/// Proxies of HTTPS_PROXY and HTTP_PROXY, except hosts in NO_PROXY.
+#[derive(Clone, Default)]
+struct Proxy {
+    https: Option<Uri>,
+    http: Option<Uri>,
+    no_proxy: Vec<String>,
+}
+
+impl Proxy {
+    fn from_env() -> Result<Self> {
+        Ok(Proxy {
+            https: proxy_uri("HTTPS_PROXY")?,
+            http: proxy_uri("HTTP_PROXY")?,
+            no_proxy: env("NO_PROXY")
+                .map(|hosts| {
+                    hosts
+                        .split(',')
+                        .map(|host| host.trim().trim_start_matches('.').to_lowercase())
+                        .filter(|host| !host.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    fn for_uri(&self, uri: &Uri) -> Option<Uri> {
+        let host = uri.host()?.to_lowercase();
+        if self.no_proxy.iter().any(|no_proxy| {
+            no_proxy == "*" || host == *no_proxy || host.ends_with(&format!(".{}", no_proxy))
+        }) {
+            return None;
+        }
+
+        match uri.scheme_str() {
+            Some("https") => self.https.clone(),
+            _ => self.http.clone(),
+        }
+    }
+}
+
+/// Value of the environment variable, the lower case name is also accepted like curl.
+fn env(name: &str) -> Option<String> {
+    [name.to_string(), name.to_lowercase()]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+}
+
+/// Only http proxies are supported, the connection to the proxy itself is not encrypted.
+fn proxy_uri(name: &str) -> Result<Option<Uri>> {
+    let value = match env(name) {
+        Some(value) if value.contains("://") => value,
+        Some(value) => format!("http://{}", value),
+        None => return Ok(None),
+    };
+
+    match value.parse::<Uri>() {
+        Ok(uri) if uri.host().is_some() && uri.scheme_str() == Some("http") => Ok(Some(uri)),
+        Ok(uri) if uri.host().is_some() => Err(ArgumentError(format!(
+            "{} proxy in {} is not supported, use an http:// proxy.",
+            uri.scheme_str().unwrap_or_default(),
+            name
+        ))),
+        _ => Err(ArgumentError(format!("invalid proxy url in {}.", name))),
+    }
+}
+
+/// Proxy-Authorization header for plain http requests, which hyper sends to the proxy as they are.
+pub(crate) fn proxy_authorization(request: &SignedRequest) -> Result<Option<String>> {
+    if request.scheme() != "http" {
+        return Ok(None);
+    }
+    let uri = format!("http://{}", request.hostname())
+        .parse::<Uri>()
+        .map_err(|e| ArgumentError(format!("invalid endpoint url: {}", e)))?;
+    Ok(Proxy::from_env()?
+        .for_uri(&uri)
+        .as_ref()
+        .and_then(basic_authorization))
+}
+
+/// Basic authorization of the user info in the proxy url, which is percent-encoded.
+fn basic_authorization(proxy: &Uri) -> Option<String> {
+    let (user_info, _) = proxy.authority()?.as_str().rsplit_once('@')?;
+    let decoded = user_info
+        .splitn(2, ':')
+        .map(|part| percent_encoding::percent_decode_str(part).decode_utf8_lossy())
+        .collect::<Vec<_>>()
+        .join(":");
+    Some(format!("Basic {}", base64::encode(decoded)))
+}
+
+async fn connect_proxy(proxy: &Uri) -> std::result::Result<TcpStream, BoxError> {
+    let host = proxy.host().ok_or("no host in the proxy url")?;
+    Ok(TcpStream::connect((host, proxy.port_u16().unwrap_or(80))).await?)
+}
+
+/// Connect to the https host through the proxy with the CONNECT method.
+async fn tunnel(proxy: Uri, uri: Uri, tls: TlsConnector) -> std::result::Result<Stream, BoxError> {
+    let host = uri.host().ok_or("no host in the request url")?.to_string();
+    let port = uri.port_u16().unwrap_or(443);
+
+    let mut stream = connect_proxy(&proxy).await?;
+    let mut request = format!("CONNECT {0}:{1} HTTP/1.1\r\nHost: {0}:{1}\r\n", host, port);
+    if let Some(authorization) = basic_authorization(&proxy) {
+        request.push_str(&format!("Proxy-Authorization: {}\r\n", authorization));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    let mut response = vec![];
+    let mut buffer = [0; 1024];
+    while !response.windows(4).any(|window| window == b"\r\n\r\n") {
+        let size = stream.read(&mut buffer).await?;
+        if size == 0 || response.len() > 8192 {
+            return Err("invalid response from the proxy".into());
+        }
+        response.extend_from_slice(&buffer[..size]);
+    }
+
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    if status_line.split_whitespace().nth(1) != Some("200") {
+        return Err(format!(
+            "proxy refused to connect to {}:{}: {}",
+            host, port, status_line
+        )
+        .into());
+    }
+
+    Ok(Stream {
+        inner: MaybeHttpsStream::Https(tls.connect(&host, stream).await?),
+        proxy: false,
+    })
+}
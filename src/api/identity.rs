@@ -0,0 +1,90 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use crate::opts::Opts;
+use rusoto_core::param::{Params, ServiceParams};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::Region;
+use yaml_rust::Yaml;
+
+/// Account of the credentials, shown not to operate on a wrong account.
+#[derive(Debug, Clone)]
+pub(crate) struct Identity {
+    pub(crate) account: String,
+    pub(crate) arn: String,
+    pub(crate) alias: Option<String>,
+}
+
+impl Identity {
+    pub(crate) fn account_label(&self) -> String {
+        match &self.alias {
+            Some(alias) => format!("{} ({})", alias, self.account),
+            None => self.account.clone(),
+        }
+    }
+
+    /// Comment lines put on top of exported yaml files.
+    pub(crate) fn yaml_header(&self) -> String {
+        format!("# account: {}\n# arn: {}\n", self.account_label(), self.arn)
+    }
+}
+
+pub(crate) async fn call(opts: &Opts) -> Result<Identity> {
+    let yaml = call_xml(opts, "sts", "GetCallerIdentity", "2011-06-15").await?;
+    let result = &yaml["get_caller_identity_result"];
+    let field = |name: &str| match &result[name] {
+        Yaml::String(value) => Ok(value.to_string()),
+        _ => Err(RusotoError(format!(
+            "{} is not in GetCallerIdentity response.",
+            name
+        ))),
+    };
+
+    // the alias is optional, the user may not be allowed to call iam.
+    let alias = match call_xml(opts, "iam", "ListAccountAliases", "2010-05-08").await {
+        Ok(yaml) => yaml["list_account_aliases_result"]["account_aliases"][0]
+            .as_str()
+            .map(str::to_string),
+        Err(_) => None,
+    };
+
+    Ok(Identity {
+        account: field("account")?,
+        arn: field("arn")?,
+        alias,
+    })
+}
+
+async fn call_xml(opts: &Opts, service_name: &str, action: &str, version: &str) -> Result<Yaml> {
+    let (response, _) = super::send_request(
+        || {
+            let region =
+                opts.request_region(service_name, &Some(api_region(service_name, opts)?))?;
+            let mut request = SignedRequest::new("POST", service_name, &region, "/");
+            let mut params = Params::new();
+
+            params.put("Action", action);
+            params.put("Version", version);
+
+            request.set_payload(Some(serde_urlencoded::to_string(&params)?));
+            request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+            Ok(request)
+        },
+        opts,
+    )
+    .await?;
+
+    super::xml_to_yaml::convert(response.body.as_ref(), &vec!["member"])
+}
+
+/// Sts has regional endpoints in every partition, but rusoto sends iam of gov cloud to the aws partition.
+fn api_region(service_name: &str, opts: &Opts) -> Result<Region> {
+    let region = opts.partition_region()?;
+    Ok(match region {
+        Region::UsGovEast1 | Region::UsGovWest1 if service_name == "iam" => Region::Custom {
+            name: Region::UsGovWest1.name().to_string(),
+            endpoint: "https://iam.us-gov.amazonaws.com".to_string(),
+        },
+        region => region,
+    })
+}
@@ -33,20 +33,13 @@ pub(crate) fn request(
 
     let encoded = Value::Object(map);
 
-    let mut request = match json_api.method {
-        JsonListMethod::Post { .. } => {
-            SignedRequest::new("POST", json_api.service_name, &opts.region()?, "/")
-        }
-        JsonListMethod::Get { path } => {
-            SignedRequest::new("GET", json_api.service_name, &opts.region()?, path)
-        }
-    };
+    let region = opts.request_region(json_api.service_name, &None)?;
+
+    let mut request = SignedRequest::new("POST", json_api.service_name, &region, "/");
 
     request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-    if let JsonListMethod::Post { target } = json_api.method {
-        request.add_header("x-amz-target", target);
-    }
+    request.add_header("x-amz-target", json_api.target);
 
     request.set_payload(Some(encoded.to_string()));
     Ok(request)
@@ -1,11 +1,13 @@
 pub(crate) mod json_helper;
+pub(crate) mod rest_helper;
 pub(crate) mod xml_helper;
 
+use crate::api::{cache, CallStats};
 use crate::error::Result;
 use crate::opts::Opts;
 use crate::service::prelude::*;
 use crate::service::AwsResource;
-use crate::service::{ListFormat, ListXml};
+use crate::service::{ListFormat, ListRest, ListXml};
 use rusoto_core::signature::SignedRequest;
 
 pub(crate) async fn call(
@@ -13,22 +15,41 @@ pub(crate) async fn call(
     parameter: &Option<String>,
     opts: &Opts,
     next_token: Option<String>,
-) -> Result<(crate::service::ResourceList, Option<String>)> {
-    let response =
-        super::send_request(request(resource, parameter, opts, next_token)?, opts).await?;
+    use_cache: bool,
+) -> Result<(crate::service::ResourceList, Option<String>, CallStats)> {
+    let key = cache::Key::new(opts, resource, "list", parameter, &next_token);
 
-    let yaml = match resource.list_api() {
-        ListFormat::Xml(ListXml { iteration_tag, .. }) => {
-            super::xml_to_yaml::convert(response.body.as_ref(), &iteration_tag)?
+    let (yaml, stats) = match cache::load(&key, opts, use_cache)? {
+        Some((yaml, cached_at)) => (yaml, CallStats::cached(cached_at)),
+        None => {
+            let (response, stats) = super::send_request(
+                || request(resource, parameter, opts, next_token.clone()),
+                opts,
+            )
+            .await?;
+
+            let yaml = match resource.list_api() {
+                ListFormat::Xml(ListXml { iteration_tag, .. }) => {
+                    super::xml_to_yaml::convert(response.body.as_ref(), &iteration_tag)?
+                }
+                ListFormat::RestXml(ListRest { iteration_tag, .. }) => {
+                    super::xml_to_yaml::convert(response.body.as_ref(), &iteration_tag)?
+                }
+                ListFormat::Json { .. } | ListFormat::RestJson { .. } => {
+                    super::json_to_yaml::convert(response.body.as_ref())?
+                }
+            };
+            cache::store(&key, &yaml, opts);
+            (yaml, stats)
         }
-        ListFormat::Json { .. } => super::json_to_yaml::convert(response.body.as_ref())?,
     };
 
     if opts.debug {
         crate::api::file::store_yaml(&yaml, "list")?;
     }
 
-    Ok(resource.list_and_next_token(&yaml))
+    let (list, next_token) = resource.list_and_next_token(&yaml);
+    Ok((list, next_token, stats))
 }
 
 fn request(
@@ -40,6 +61,12 @@ fn request(
     match &resource.info().list_api.format {
         ListFormat::Xml(xml_api) => xml_helper::request(opts, next_token, parameter, xml_api),
         ListFormat::Json(json_api) => json_helper::request(opts, next_token, parameter, json_api),
+        ListFormat::RestJson(rest_api) => {
+            rest_helper::request(opts, next_token, parameter, rest_api, true)
+        }
+        ListFormat::RestXml(rest_api) => {
+            rest_helper::request(opts, next_token, parameter, rest_api, false)
+        }
     }
 }
 
@@ -0,0 +1,38 @@
+use crate::service::prelude::*;
+use serde_json::Value;
+
+pub(crate) fn request(
+    opts: &Opts,
+    next_token: Option<String>,
+    parameter: &Option<String>,
+    rest_api: &ListRest,
+    json: bool,
+) -> Result<SignedRequest> {
+    let mut bindings = rest_api
+        .params
+        .iter()
+        .map(|(location, value)| (location, Value::from(*value)))
+        .collect::<Vec<_>>();
+
+    if let Some((location, max)) = &rest_api.limit {
+        bindings.push((location, Value::from(*max)));
+    }
+
+    if let (Some(location), Some(next_token)) = (&rest_api.token, next_token) {
+        bindings.push((location, Value::String(next_token)));
+    }
+
+    if let (Some(location), Some(parameter)) = (&rest_api.parameter, parameter) {
+        bindings.push((location, Value::String(parameter.to_owned())));
+    }
+
+    crate::api::rest::request(
+        opts,
+        &rest_api.method,
+        rest_api.service_name,
+        rest_api.path,
+        &rest_api.region,
+        json,
+        bindings,
+    )
+}
@@ -7,11 +7,7 @@ pub(crate) fn request(
     parameter: &Option<String>,
     xml_api: &ListXml,
 ) -> Result<SignedRequest> {
-    let region = if let Some(region) = xml_api.region.clone() {
-        region
-    } else {
-        opts.region()?
-    };
+    let region = opts.request_region(xml_api.service_name, &xml_api.region)?;
 
     let mut path = xml_api.path.0.to_string();
     if let Some(parameter) = parameter {
@@ -1,40 +1,184 @@
+mod aws_error;
+pub(crate) mod cache;
+pub(crate) mod credentials;
 pub(crate) mod file;
 pub(crate) mod get;
+pub(crate) mod http;
+pub(crate) mod identity;
 pub(crate) mod json_to_yaml;
 pub(crate) mod list;
+pub(crate) mod profile;
+mod record;
+pub(crate) mod rest;
+pub(crate) mod retry;
 pub(crate) mod xml_to_yaml;
 
 use crate::error::Error::*;
 use crate::error::Result;
 use crate::opts::Opts;
+use chrono::prelude::*;
 use rusoto_core::request::BufferedHttpResponse;
 use rusoto_core::signature::SignedRequest;
-use rusoto_core::{Client, HttpClient};
-use rusoto_credential::ChainProvider;
-
-async fn send_request(request: SignedRequest, opts: &Opts) -> Result<BufferedHttpResponse> {
-    let mut response = Client::new_with(ChainProvider::default(), HttpClient::new()?)
-        .sign_and_dispatch(request)
-        .await
-        .map_err(|e| RusotoError(format!("{:?}", e)))?;
-
-    let response = response
-        .buffer()
-        .await
-        .map_err(|e| RusotoError(format!("{}", e)))?;
-
-    if !response.status.is_success() {
-        return Err(RusotoError(
-            String::from_utf8(response.body.as_ref().to_vec()).unwrap_or("".to_string()),
-        ));
+use rusoto_core::{DispatchSignedRequest, HttpDispatchError};
+use rusoto_credential::AwsCredentials;
+use std::time::Duration;
+
+type HttpDispatchResult = std::result::Result<BufferedHttpResponse, HttpDispatchError>;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CallStats {
+    pub(crate) retry_count: usize,
+    pub(crate) cached_at: Option<DateTime<Local>>,
+    /// Time spent to open new connections, None when pooled connections are reused.
+    pub(crate) connect: Option<Duration>,
+}
+
+impl CallStats {
+    pub(crate) fn cached(cached_at: DateTime<Local>) -> Self {
+        CallStats {
+            cached_at: Some(cached_at),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn summary(&self) -> String {
+        if let Some(cached_at) = &self.cached_at {
+            return format!(", cached {} ago", cache::age(cached_at));
+        }
+
+        let connect = match self.connect {
+            Some(connect) => format!(", connect {} ms", connect.as_millis()),
+            None => "".to_string(),
+        };
+
+        match self.retry_count {
+            0 => connect,
+            1 => format!("{}, 1 retry", connect),
+            count => format!("{}, {} retries", connect, count),
+        }
     }
+}
+
+async fn send_request<F>(build_request: F, opts: &Opts) -> Result<(BufferedHttpResponse, CallStats)>
+where
+    F: Fn() -> Result<SignedRequest>,
+{
+    send(build_request, opts, None, opts.max_retries()).await
+}
+
+/// Request signed with the given credentials like AssumeRole, which is neither recorded nor replayed.
+async fn send_signed<F>(
+    build_request: F,
+    opts: &Opts,
+    credentials: &AwsCredentials,
+    max_retries: usize,
+) -> Result<(BufferedHttpResponse, CallStats)>
+where
+    F: Fn() -> Result<SignedRequest>,
+{
+    send(build_request, opts, Some(credentials), max_retries).await
+}
+
+async fn send<F>(
+    build_request: F,
+    opts: &Opts,
+    credentials: Option<&AwsCredentials>,
+    max_retries: usize,
+) -> Result<(BufferedHttpResponse, CallStats)>
+where
+    F: Fn() -> Result<SignedRequest>,
+{
+    let mut stats = CallStats::default();
+
+    let response = loop {
+        let retry_remains = stats.retry_count < max_retries;
+
+        let request = build_request()?;
+        let target = format!("{} in {}", request.service, request.region.name());
+        let dispatching = dispatch(request, opts, credentials, &mut stats);
+        let response = tokio::time::timeout(Duration::from_secs(opts.timeout()), dispatching)
+            .await
+            .map_err(|_| TimeoutError(opts.timeout()))??;
+
+        let reason = match response {
+            Ok(response) if response.status.is_success() => break response,
+            Ok(response) if retry_remains && retry::is_retryable(&response) => {
+                match aws_error::parse(&response).code {
+                    code if code.is_empty() => response.status.to_string(),
+                    code => code,
+                }
+            }
+            Ok(response) => {
+                let mut error = aws_error::parse(&response);
+                error.retry_count = stats.retry_count;
+                return Err(AwsApiError(error));
+            }
+            Err(e) if retry_remains && retry::is_retryable_error(&e) => e.to_string(),
+            Err(e) => return Err(RusotoError(format!("{}{}", e, stats.summary()))),
+        };
+
+        let delay = retry::delay(stats.retry_count);
+        let message = format!(
+            "retry {} ({}/{}) in {} ms: {}",
+            target,
+            stats.retry_count + 1,
+            max_retries,
+            delay.as_millis(),
+            reason
+        );
+        match opts.output {
+            Some(_) => eprintln!("hrkk: {}", message),
+            None => opts.retries.push(message),
+        }
+        tokio::time::delay_for(delay).await;
+        stats.retry_count += 1;
+    };
 
     if !response.body.is_empty() {
         if opts.debug {
             file::store_response(response.body.as_ref())?;
         }
-        Ok(response)
+        Ok((response, stats))
     } else {
         Err(RusotoError("response body is empty.".to_string()))
     }
 }
+
+async fn dispatch(
+    mut request: SignedRequest,
+    opts: &Opts,
+    credentials: Option<&AwsCredentials>,
+    stats: &mut CallStats,
+) -> Result<HttpDispatchResult> {
+    let identity = record::identity(&request);
+    let is_recorded = credentials.is_none();
+    if let (Some(dir), true) = (&opts.replay, is_recorded) {
+        return Ok(Ok(record::load(dir, &identity)?));
+    }
+
+    let credentials = match credentials {
+        Some(credentials) => credentials.clone(),
+        None => credentials::get(opts).await?,
+    };
+    request.sign(&credentials);
+    if let Some(authorization) = http::proxy_authorization(&request)? {
+        request.add_header("Proxy-Authorization", &authorization);
+    }
+
+    let client = opts.http_clients.client(opts, &request.region)?;
+    let (response, connect) = http::with_connect_time(async {
+        match client.dispatch(request, None).await {
+            Ok(mut response) => response.buffer().await,
+            Err(e) => Err(e),
+        }
+    })
+    .await;
+    if let Some(connect) = connect {
+        stats.connect = Some(stats.connect.unwrap_or_default() + connect);
+    }
+
+    if let (Some(dir), Ok(response), true) = (&opts.record, &response, is_recorded) {
+        record::store(dir, identity, response)?;
+    }
+    Ok(response)
+}
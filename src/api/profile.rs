@@ -0,0 +1,91 @@
+use crate::error::Result;
+use rusoto_credential::{AwsCredentials, ChainProvider, ProfileProvider, ProvideAwsCredentials};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Credentials of a profile named by --profile, --profiles or source_profile come only from the profile files.
+/// Without a named profile, environment variables and instance roles take precedence like the aws cli.
+pub(crate) async fn credentials(profile: &str, named: bool) -> Result<AwsCredentials> {
+    let provider = ProfileProvider::with_default_credentials(profile)?;
+    if named {
+        Ok(provider.credentials().await?)
+    } else {
+        Ok(ChainProvider::with_profile_provider(provider)
+            .credentials()
+            .await?)
+    }
+}
+
+/// Profile names in ~/.aws/config and ~/.aws/credentials.
+pub(crate) fn names() -> Vec<String> {
+    let mut names = vec![];
+    for (path, is_config) in &[(config_path(), true), (credentials_path(), false)] {
+        for (section, _) in sections(path) {
+            let name = match (is_config, section.strip_prefix("profile ")) {
+                (true, Some(name)) => name.trim().to_string(),
+                _ => section,
+            };
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// Region of a profile in ~/.aws/config.
+pub(crate) fn region(profile: &str) -> Option<String> {
+    properties(profile).get("region").cloned()
+}
+
+/// Properties of a profile in ~/.aws/config like `region` or `role_arn`.
+pub(crate) fn properties(profile: &str) -> HashMap<String, String> {
+    sections(&config_path())
+        .into_iter()
+        .find(|(section, _)| {
+            section == profile || section.strip_prefix("profile ").map(str::trim) == Some(profile)
+        })
+        .map(|(_, properties)| properties)
+        .unwrap_or_default()
+}
+
+fn config_path() -> Option<PathBuf> {
+    aws_file("AWS_CONFIG_FILE", "config")
+}
+
+fn credentials_path() -> Option<PathBuf> {
+    aws_file("AWS_SHARED_CREDENTIALS_FILE", "credentials")
+}
+
+fn aws_file(env_name: &str, file_name: &str) -> Option<PathBuf> {
+    match std::env::var(env_name) {
+        Ok(path) if !path.is_empty() => Some(PathBuf::from(path)),
+        _ => dirs::home_dir().map(|mut path| {
+            path.push(".aws");
+            path.push(file_name);
+            path
+        }),
+    }
+}
+
+/// Sections of an ini file in the order of appearance.
+fn sections(path: &Option<PathBuf>) -> Vec<(String, HashMap<String, String>)> {
+    let text = match path.as_ref().and_then(|path| fs::read_to_string(path).ok()) {
+        Some(text) => text,
+        None => return vec![],
+    };
+
+    let mut sections: Vec<(String, HashMap<String, String>)> = vec![];
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') && line.ends_with(']') {
+            sections.push((line[1..line.len() - 1].trim().to_string(), HashMap::new()));
+        } else if let (Some((_, properties)), Some(index)) = (sections.last_mut(), line.find('=')) {
+            properties.insert(
+                line[..index].trim().to_string(),
+                line[index + 1..].trim().to_string(),
+            );
+        }
+    }
+    sections
+}
@@ -0,0 +1,90 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use rusoto_core::request::BufferedHttpResponse;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// What identifies a request regardless of the signing time and credentials.
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct Identity {
+    service: String,
+    region: String,
+    method: String,
+    path: String,
+    params: BTreeMap<String, Option<String>>,
+    target: Option<String>,
+    payload: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    identity: Identity,
+    status: u16,
+    body: String,
+}
+
+pub(crate) fn identity(request: &SignedRequest) -> Identity {
+    Identity {
+        service: request.service.clone(),
+        region: request.region.name().to_string(),
+        method: request.method.clone(),
+        path: request.path.clone(),
+        params: request.params.clone(),
+        target: request
+            .headers()
+            .get("x-amz-target")
+            .and_then(|values| values.first())
+            .map(|value| String::from_utf8_lossy(value).to_string()),
+        payload: match &request.payload {
+            Some(SignedRequestPayload::Buffer(bytes)) => {
+                Some(String::from_utf8_lossy(bytes).to_string())
+            }
+            _ => None,
+        },
+    }
+}
+
+impl Identity {
+    fn file_path(&self, dir: &str) -> Result<PathBuf> {
+        let mut path = PathBuf::from(dir);
+        path.push(format!(
+            "{}-{}.json",
+            self.service,
+            super::cache::hash(&serde_json::to_string(self)?)
+        ));
+        Ok(path)
+    }
+}
+
+pub(crate) fn store(dir: &str, identity: Identity, response: &BufferedHttpResponse) -> Result<()> {
+    let path = identity.file_path(dir)?;
+    let record = Record {
+        identity,
+        status: response.status.as_u16(),
+        body: String::from_utf8_lossy(response.body.as_ref()).to_string(),
+    };
+
+    fs::create_dir_all(dir).or(Err(UnableToWriteFileError(dir.to_string())))?;
+    fs::write(&path, serde_json::to_string_pretty(&record)?)
+        .or(Err(UnableToWriteFileError(format!("{}", path.display()))))
+}
+
+pub(crate) fn load(dir: &str, identity: &Identity) -> Result<BufferedHttpResponse> {
+    let path = identity.file_path(dir)?;
+    let text = fs::read_to_string(&path).or(Err(ReplayMissError(format!(
+        "{} ({:?})",
+        path.display(),
+        identity
+    ))))?;
+    let record: Record = serde_json::from_str(&text)?;
+
+    Ok(BufferedHttpResponse {
+        status: http::StatusCode::from_u16(record.status)
+            .unwrap_or(http::StatusCode::INTERNAL_SERVER_ERROR),
+        body: record.body.into(),
+        headers: http::HeaderMap::default(),
+    })
+}
@@ -0,0 +1,74 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use crate::opts::Opts;
+use crate::service::{Location, Method};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::Region;
+use serde_json::{Map, Value};
+
+/// Request of rest-json and rest-xml apis, each value is put in its location.
+pub(crate) fn request(
+    opts: &Opts,
+    method: &Method,
+    service_name: &str,
+    path: &str,
+    region: &Option<Region>,
+    json: bool,
+    bindings: Vec<(&Location, Value)>,
+) -> Result<SignedRequest> {
+    let mut path = path.to_string();
+    let mut queries = vec![];
+    let mut headers = vec![];
+    let mut body = Map::new();
+
+    for (location, value) in bindings {
+        match location {
+            // rusoto encodes the path when the request is sent.
+            Location::Path(name) => path = path.replace(&format!("{{{}}}", name), &text(&value)),
+            Location::Query(name) => queries.push((name, text(&value))),
+            Location::Header(name) => headers.push((name, text(&value))),
+            Location::Body(name) if json => {
+                body.insert(name.to_string(), value);
+            }
+            Location::Body(name) => {
+                return Err(SettingError(format!(
+                    "{} can not be in the body of rest-xml requests.",
+                    name
+                )))
+            }
+        }
+    }
+
+    if let Some(start) = path.find('{') {
+        return Err(SettingError(format!(
+            "no value for {} in the path.",
+            &path[start..]
+        )));
+    }
+
+    let region = opts.request_region(service_name, region)?;
+    let mut request = SignedRequest::new(method.to_str(), service_name, &region, &path);
+
+    for (name, value) in queries {
+        request.add_param(*name, &value);
+    }
+    for (name, value) in headers {
+        request.add_header(*name, &value);
+    }
+
+    if json {
+        request.set_content_type("application/json".to_owned());
+        if let Method::Post = method {
+            request.set_payload(Some(Value::Object(body).to_string()));
+        }
+    }
+
+    Ok(request)
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.to_owned(),
+        value => value.to_string(),
+    }
+}
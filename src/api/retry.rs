@@ -0,0 +1,80 @@
+use rand::Rng;
+use rusoto_core::request::BufferedHttpResponse;
+use rusoto_core::HttpDispatchError;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const BASE_DELAY_MILLIS: u64 = 100;
+const MAX_DELAY_MILLIS: u64 = 20_000;
+
+const RETRYABLE_CODES: [&str; 16] = [
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestThrottled",
+    "RequestThrottledException",
+    "RequestLimitExceeded",
+    "TooManyRequestsException",
+    "ProvisionedThroughputExceededException",
+    "TransactionInProgressException",
+    "PriorRequestNotComplete",
+    "EC2ThrottledException",
+    "BandwidthLimitExceeded",
+    "SlowDown",
+    "RequestTimeout",
+    "InternalError",
+    "ServiceUnavailable",
+];
+
+pub(crate) fn is_retryable(response: &BufferedHttpResponse) -> bool {
+    let status = response.status.as_u16();
+    if status == 429 || (500..600).contains(&status) {
+        return true;
+    }
+
+    RETRYABLE_CODES.contains(&&super::aws_error::parse(response).code[..])
+}
+
+/// Transport errors which may succeed next time, tls errors or unknown hosts fail the same way again.
+const RETRYABLE_ERRORS: [&str; 4] = [
+    "connection reset",
+    "connection closed before message completed",
+    "broken pipe",
+    "timed out",
+];
+
+pub(crate) fn is_retryable_error(error: &HttpDispatchError) -> bool {
+    let message = error.to_string().to_lowercase();
+    RETRYABLE_ERRORS
+        .iter()
+        .any(|retryable| message.contains(retryable))
+}
+
+/// Retries logged as they happen, the tui shows them in the log and --output prints them to stderr.
+#[derive(Clone, Default)]
+pub(crate) struct Log(Arc<Mutex<Vec<String>>>);
+
+impl std::fmt::Debug for Log {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Log")
+    }
+}
+
+impl Log {
+    pub(crate) fn push(&self, message: String) {
+        self.0.lock().unwrap().push(message);
+    }
+
+    pub(crate) fn take(&self) -> Vec<String> {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+/// Exponential backoff with full jitter.
+pub(crate) fn delay(retry_count: usize) -> Duration {
+    let max = std::cmp::min(
+        BASE_DELAY_MILLIS.saturating_mul(2_u64.saturating_pow(retry_count as u32)),
+        MAX_DELAY_MILLIS,
+    );
+    Duration::from_millis(rand::thread_rng().gen_range(0, max + 1))
+}
@@ -2,114 +2,200 @@ use crate::error::Error;
 use crate::error::Result;
 use inflector::Inflector;
 use linked_hash_map::LinkedHashMap;
+use xml::attribute::OwnedAttribute;
+use xml::name::OwnedName;
 use xml::reader::ParserConfig;
 use xml::reader::XmlEvent;
 use xml::EventReader;
 use yaml_rust::Yaml;
 
+/// Convert xml to yaml while reading the events, the root tag itself is dropped.
+///
+/// Repeated sibling tags become arrays. Tags in `iteration_tag` are always arrays,
+/// even with one item, and a tag whose first child is one of them is the array itself.
 pub(crate) fn convert(source: &[u8], iteration_tag: &Vec<&str>) -> Result<Yaml> {
     let reader = EventReader::new_with_config(source, ParserConfig::new().trim_whitespace(false));
-    let elements = to_elements(reader)?;
-    let (yaml, _) = convert_element(&elements, 0, iteration_tag)?;
+    let mut stack: Vec<Node> = vec![];
 
-    Ok(yaml)
-}
-
-fn convert_element(
-    elements: &Vec<Element>,
-    index: usize,
-    iteration_tag: &Vec<&str>,
-) -> Result<(Yaml, usize)> {
-    match elements.get(index + 1) {
-        Some(Element::StartTag(name)) => {
-            // grep "ojb.push" in generated.rs
-            if iteration_tag.contains(&&name[..]) {
-                // if name == "item" || name == "member" || name == "DBInstance" || name == "Subnet" {
-                convert_array(elements, index, iteration_tag)
-            } else {
-                convert_hash(elements, index, iteration_tag)
+    for event in reader {
+        match event {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => stack.push(Node::new(name.local_name, &attributes)),
+            Ok(XmlEvent::Characters(text)) | Ok(XmlEvent::CData(text)) => {
+                if let Some(node) = stack.last_mut() {
+                    node.text.push_str(&text);
+                }
             }
+            Ok(XmlEvent::EndElement { .. }) => {
+                let node = stack
+                    .pop()
+                    .ok_or_else(|| xml_error(&stack, "unexpected end tag"))?;
+                let yaml = node.to_yaml(iteration_tag);
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push((node.name, yaml)),
+                    None => return Ok(yaml),
+                }
+            }
+            Ok(_) => (),
+            Err(e) => return Err(xml_error(&stack, &e.to_string())),
         }
-        Some(Element::Value(string)) => Ok((Yaml::String(string.to_owned()), 3)),
-        Some(Element::EndTag(_)) => Ok((Yaml::String("".to_string()), 2)),
-        _ => Err(Error::XmlError),
     }
+
+    Err(xml_error(&stack, "unexpected end of document"))
 }
 
-fn convert_array(
-    elements: &Vec<Element>,
-    index: usize,
-    iteration_tag: &Vec<&str>,
-) -> Result<(Yaml, usize)> {
-    let mut consumed = 1;
-    let mut arr = vec![];
-
-    loop {
-        match elements.get(index + consumed) {
-            Some(Element::StartTag(_)) => {
-                let (yaml, forward) = convert_element(elements, index + consumed, iteration_tag)?;
-                consumed += forward;
-                arr.push(yaml);
-            }
-            Some(Element::EndTag(_)) => {
-                consumed += 1;
-                break;
+/// Error with the path of tags being read, like `ListBucketResult/Contents/Key`.
+fn xml_error(stack: &[Node], message: &str) -> Error {
+    let path = stack
+        .iter()
+        .map(|node| node.name.as_str())
+        .collect::<Vec<&str>>()
+        .join("/");
+    Error::InvalidXml(format!("{} at /{}", message, path))
+}
+
+struct Node {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<(String, Yaml)>,
+    text: String,
+}
+
+impl Node {
+    fn new(name: String, attributes: &[OwnedAttribute]) -> Self {
+        Node {
+            name,
+            attributes: attributes
+                .iter()
+                .map(|attribute| (attribute_key(&attribute.name), attribute.value.clone()))
+                .collect(),
+            children: vec![],
+            text: String::new(),
+        }
+    }
+
+    fn to_yaml(&self, iteration_tag: &Vec<&str>) -> Yaml {
+        match self.children.first() {
+            None if self.attributes.is_empty() => Yaml::String(self.text.clone()),
+            Some((name, _))
+                if self.attributes.is_empty() && iteration_tag.contains(&name.as_str()) =>
+            {
+                Yaml::Array(self.children.iter().map(|(_, yaml)| yaml.clone()).collect())
             }
-            _ => return Err(Error::XmlError),
+            _ => self.to_hash(iteration_tag),
         }
     }
 
-    Ok((Yaml::Array(arr), consumed))
-}
+    fn to_hash(&self, iteration_tag: &Vec<&str>) -> Yaml {
+        let mut map = LinkedHashMap::new();
+        for (key, value) in &self.attributes {
+            map.insert(Yaml::String(key.clone()), Yaml::String(value.clone()));
+        }
+        if self.children.is_empty() && !self.text.is_empty() {
+            map.insert(
+                Yaml::String("value".to_string()),
+                Yaml::String(self.text.clone()),
+            );
+        }
 
-fn convert_hash(
-    elements: &Vec<Element>,
-    index: usize,
-    iteration_tag: &Vec<&str>,
-) -> Result<(Yaml, usize)> {
-    let mut consumed = 1;
-    let mut map = LinkedHashMap::new();
-
-    loop {
-        match elements.get(index + consumed) {
-            Some(Element::StartTag(name)) => {
-                let (yaml, forward) = convert_element(elements, index + consumed, iteration_tag)?;
-                consumed += forward;
-                let key = Yaml::String(name.to_snake_case());
-                if map.contains_key(&key) {
-                    return Err(Error::DuplicatedXmlTag(name.to_owned()));
+        let mut groups: LinkedHashMap<&str, Vec<Yaml>> = LinkedHashMap::new();
+        for (name, yaml) in &self.children {
+            match groups.get_mut(name.as_str()) {
+                Some(yamls) => yamls.push(yaml.clone()),
+                None => {
+                    groups.insert(name.as_str(), vec![yaml.clone()]);
                 }
-                map.insert(key, yaml);
             }
-            Some(Element::EndTag(_)) => {
-                consumed += 1;
-                break;
-            }
-            _ => return Err(Error::XmlError),
         }
-    }
+        for (name, mut yamls) in groups {
+            let value = if yamls.len() > 1 || iteration_tag.contains(&name) {
+                Yaml::Array(yamls)
+            } else {
+                yamls.remove(0)
+            };
+            map.insert(Yaml::String(name.to_snake_case()), value);
+        }
 
-    Ok((Yaml::Hash(map), consumed))
+        Yaml::Hash(map)
+    }
 }
 
-#[derive(Debug)]
-enum Element {
-    StartTag(String),
-    EndTag(String),
-    Value(String),
+/// `xsi:type` becomes `xsi_type`, namespace declarations are not attributes in xml-rs.
+fn attribute_key(name: &OwnedName) -> String {
+    match &name.prefix {
+        Some(prefix) => format!("{}_{}", prefix, name.local_name).to_snake_case(),
+        None => name.local_name.to_snake_case(),
+    }
 }
 
-fn to_elements(reader: EventReader<&[u8]>) -> Result<Vec<Element>> {
-    let mut e = vec![];
-    for event in reader {
-        match event {
-            Ok(XmlEvent::StartElement { name, .. }) => e.push(Element::StartTag(name.local_name)),
-            Ok(XmlEvent::Characters(string)) => e.push(Element::Value(string)),
-            Ok(XmlEvent::EndElement { name }) => e.push(Element::EndTag(name.local_name)),
-            Ok(XmlEvent::EndDocument {}) => break,
-            Ok(_) => (),
-            Err(_) => Err(Error::XmlError)?,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yaml_rust::YamlLoader;
+
+    fn load(yaml: &str) -> Yaml {
+        YamlLoader::load_from_str(yaml).unwrap().remove(0)
+    }
+
+    fn to_yaml(xml: &str, iteration_tag: &[&str]) -> Yaml {
+        convert(xml.as_bytes(), &iteration_tag.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn repeated_siblings() {
+        assert_eq!(
+            to_yaml("<R><Item>a</Item></R>", &[]),
+            load("item: a"),
+            "one item without iteration_tag is a hash"
+        );
+        assert_eq!(
+            to_yaml("<R><Item>a</Item><Item>b</Item><Marker/></R>", &[]),
+            load("{item: [a, b], marker: ''}"),
+            "two items are an array"
+        );
+    }
+
+    #[test]
+    fn iteration_tag() {
+        assert_eq!(
+            to_yaml(
+                "<R><TagSet><item><Key>k</Key></item></TagSet></R>",
+                &["item"]
+            ),
+            load("tag_set: [{key: k}]")
+        );
+        assert_eq!(
+            to_yaml(
+                "<R><Owner>o</Owner><item><Key>k</Key></item></R>",
+                &["item"]
+            ),
+            load("{owner: o, item: [{key: k}]}")
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        assert_eq!(
+            to_yaml(
+                r#"<R xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"><Grantee xsi:type="User" id="1">me</Grantee></R>"#,
+                &[]
+            ),
+            load("grantee: {xsi_type: User, id: '1', value: me}")
+        );
+        assert_eq!(
+            to_yaml(r#"<R><Grantee id="1"><Name>me</Name></Grantee></R>"#, &[]),
+            load("grantee: {id: '1', name: me}")
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        for xml in &["", "<R><Item>a</R>", "<R><Item>a</Item>"] {
+            match convert(xml.as_bytes(), &vec![]) {
+                Err(Error::InvalidXml(_)) => (),
+                other => panic!("{}: {:?}", xml, other),
+            }
         }
     }
-    Ok(e)
 }
@@ -0,0 +1,82 @@
+use crate::error::Result;
+use crate::service::{all_resources, AwsResource};
+use serde::Serialize;
+
+/// A resource type as "hrkk resources --output json|yaml" prints it.
+#[derive(Serialize)]
+struct Entry {
+    /// Sub command like "logs log-stream <log_group_name>".
+    command: String,
+    service: String,
+    resource: String,
+    /// Attribute of the list api response used as the name of each resource.
+    key_attribute: Option<&'static str>,
+    header: Vec<&'static str>,
+    list_api: String,
+    list_api_document: String,
+    get_api: Option<String>,
+    get_api_document: Option<String>,
+    /// Console url with `{region}` and the other `{...}` placeholders of the resource.
+    console_url: Option<String>,
+}
+
+/// Print every resource type of `all_resources()` as json, yaml or a markdown table.
+pub(crate) fn print(output: &str) -> Result<()> {
+    let resources = all_resources();
+    match output {
+        "json" => println!("{}", serde_json::to_string_pretty(&entries(&resources))?),
+        "yaml" => println!("{}", serde_yaml::to_string(&entries(&resources))?),
+        _ => print!("{}", markdown(&resources)),
+    }
+    Ok(())
+}
+
+fn entries(resources: &[Box<dyn AwsResource>]) -> Vec<Entry> {
+    resources
+        .iter()
+        .map(|resource| {
+            let info = resource.info();
+            Entry {
+                command: command_with_parameter(&**resource),
+                service: resource.service_name(),
+                resource: resource.command_name(),
+                key_attribute: info.key_attribute,
+                header: info.header.clone(),
+                list_api: info.list_api.format.name(),
+                list_api_document: info.list_api.document.url(),
+                get_api: info.get_api.as_ref().map(|get| get.format.name()),
+                get_api_document: info.get_api.as_ref().map(|get| get.document.url()),
+                console_url: info.resource_url.as_ref().map(|url| url.url("{region}")),
+            }
+        })
+        .collect()
+}
+
+fn command_with_parameter(resource: &dyn AwsResource) -> String {
+    let command = format!("{} {}", resource.service_name(), resource.command_name());
+    match resource.info().list_api.format.parameter_name() {
+        Some(parameter) => format!("{} <{}>", command, parameter),
+        None => command,
+    }
+}
+
+/// The table in README.md, regenerated by ci/readme.sh.
+fn markdown(resources: &[Box<dyn AwsResource>]) -> String {
+    let mut text = "| command | list api | get api | columns |\n|---|---|---|---|\n".to_string();
+    for resource in resources {
+        let info = resource.info();
+        let get = match &info.get_api {
+            Some(get) => format!("[{}]({})", get.format.name(), get.document.url()),
+            None => "-".to_string(),
+        };
+        text.push_str(&format!(
+            "| `{}` | [{}]({}) | {} | {} |\n",
+            command_with_parameter(&**resource),
+            info.list_api.format.name(),
+            info.list_api.document.url(),
+            get,
+            info.header.join(", ")
+        ));
+    }
+    text
+}
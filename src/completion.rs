@@ -0,0 +1,166 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use crate::opts::Opts;
+use crate::service::{all_resources, AwsResource, ExecuteTarget};
+use std::io::Write;
+use structopt::clap::Shell;
+use structopt::StructOpt;
+
+/// Completion script of clap with the cached keys of parameter resources,
+/// like log group names for `hrkk logs log-stream <log_group_name>`.
+pub(crate) fn print_script(shell: &str, opts: &Opts) -> Result<()> {
+    let shell = shell.parse::<Shell>().map_err(ArgumentError)?;
+    let mut script = vec![];
+    Opts::clap().gen_completions_to("hrkk", shell, &mut script);
+    let script = String::from_utf8_lossy(&script);
+    let parameters = parameter_commands(opts);
+
+    let script = match shell {
+        Shell::Bash => format!("{}\n{}", script, bash(&parameters)),
+        Shell::Zsh => zsh(&script, &parameters),
+        _ => format!("{}\n{}", script, fish(&parameters)),
+    };
+    std::io::stdout().write_all(script.as_bytes())?;
+    Ok(())
+}
+
+/// Cached keys of the resource in the targets of `-p` and `-r`,
+/// run `hrkk --cache-ttl <seconds> <service> <resource>` once to cache them.
+pub(crate) fn print_keys(service: &str, resource: &str, opts: &Opts) -> Result<()> {
+    let resource = all_resources()
+        .into_iter()
+        .find(|r| r.service_name() == service && r.command_name() == resource)
+        .ok_or_else(|| ArgumentError(format!("no resource {} {}", service, resource)))?;
+
+    let mut keys = vec![];
+    for target in resource.targets(opts) {
+        for yaml in crate::api::cache::lists(&opts.with_target(&target), &resource.name()) {
+            for (_, item) in resource.list_and_next_token(&yaml).0 {
+                keys.push(resource.resource_name(&item));
+            }
+        }
+    }
+    keys.sort();
+    keys.dedup();
+
+    for key in keys {
+        println!("{}", key);
+    }
+    Ok(())
+}
+
+/// "<service> <resource>" commands and the "<service> <resource>" of their parameters.
+fn parameter_commands(opts: &Opts) -> Vec<(String, String)> {
+    let command = |resource: &dyn AwsResource| {
+        format!("{} {}", resource.service_name(), resource.command_name())
+    };
+    all_resources()
+        .iter()
+        .filter_map(|resource| match resource.without_param(opts) {
+            ExecuteTarget::ParameterFromResource { param_resource } => {
+                Some((command(&**resource), command(&*param_resource)))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn bash(parameters: &[(String, String)]) -> String {
+    let cases = parameters
+        .iter()
+        .map(|(command, parameter)| {
+            format!(
+                "        \"{}\") COMPREPLY=($(compgen -W \"$(hrkk $(_hrkk_options) complete {} 2>/dev/null)\" -- \"${{cur}}\")); return 0 ;;\n",
+                command, parameter
+            )
+        })
+        .collect::<String>();
+    format!(
+        r#"_hrkk_options() {{
+    local i
+    for ((i = 1; i < COMP_CWORD - 1; i++)); do
+        case "${{COMP_WORDS[i]}}" in
+            -p|--profile|-r|--region) echo "${{COMP_WORDS[i]}} ${{COMP_WORDS[i+1]}}" ;;
+        esac
+    done
+}}
+
+_hrkk_resources() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    case "${{COMP_WORDS[COMP_CWORD-2]}} ${{COMP_WORDS[COMP_CWORD-1]}}" in
+{}    esac
+    _hrkk "$@"
+}}
+
+complete -F _hrkk_resources -o bashdefault -o default hrkk
+"#,
+        cases
+    )
+}
+
+/// The zsh script is the body of the autoloaded `_hrkk` which calls `_hrkk "$@"` at the end,
+/// so clap's `_hrkk` is renamed and ours takes its name to stay registered after the first call.
+fn zsh(script: &str, parameters: &[(String, String)]) -> String {
+    let cases = parameters
+        .iter()
+        .map(|(command, parameter)| {
+            format!(
+                "        \"{}\") compadd -- ${{(f)\"$(hrkk $(_hrkk_options) complete {} 2>/dev/null)\"}}; return ;;\n",
+                command, parameter
+            )
+        })
+        .collect::<String>();
+    let (header, script) = script.split_at(script.find('\n').unwrap_or(0));
+    format!(
+        r#"{}
+
+_hrkk_options() {{
+    local i
+    for ((i = 2; i < CURRENT - 1; i++)); do
+        case "${{words[i]}}" in
+            -p|--profile|-r|--region) echo "${{words[i]}} ${{words[i+1]}}" ;;
+        esac
+    done
+}}
+
+_hrkk() {{
+    case "${{words[CURRENT-2]}} ${{words[CURRENT-1]}}" in
+{}    esac
+    _hrkk_clap "$@"
+}}
+
+{}
+"#,
+        header,
+        cases,
+        script.trim().replacen("\n_hrkk() {", "\n_hrkk_clap() {", 1)
+    )
+}
+
+fn fish(parameters: &[(String, String)]) -> String {
+    let completes = parameters
+        .iter()
+        .map(|(command, parameter)| {
+            let words = command.split(' ').collect::<Vec<&str>>();
+            format!(
+                "complete -c hrkk -n \"__fish_seen_subcommand_from {}; and __fish_seen_subcommand_from {}\" -f -a \"(hrkk (__hrkk_options) complete {} 2>/dev/null)\"\n",
+                words[0], words[1], parameter
+            )
+        })
+        .collect::<String>();
+    format!(
+        r#"function __hrkk_options
+    set -l words (commandline -opc)
+    for i in (seq 2 (math (count $words) - 1))
+        switch $words[$i]
+            case -p --profile -r --region
+                echo $words[$i]
+                echo $words[(math $i + 1)]
+        end
+    end
+end
+
+{}"#,
+        completes
+    )
+}
@@ -4,19 +4,19 @@ pub(crate) type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub(crate) enum Error {
-    #[error("AWS API call error: {0:}")]
+    #[error("invalid region: {0}")]
     ParseRegionError(#[from] rusoto_signature::region::ParseRegionError),
 
-    #[error("TlsError: {0:?}")]
-    TlsError(#[from] rusoto_core::request::TlsError),
+    #[error("tls error: {0}")]
+    TlsError(String),
 
-    #[error("aws credentials error: {0:?}")]
+    #[error("aws credentials error: {0}")]
     AwsCredentialsError(#[from] rusoto_credential::CredentialsError),
 
-    #[error("Yaml format error: {0:?}")]
+    #[error("yaml format error: {0}")]
     CacheYamlFormatError(#[from] yaml_rust::emitter::EmitError),
 
-    #[error("Unable to write file at {0:?}.")]
+    #[error("unable to write file at {0}")]
     UnableToWriteFileError(String),
 
     #[error("{0:}")]
@@ -25,27 +25,130 @@ pub(crate) enum Error {
     #[error("rusoto error: {0:}")]
     RusotoError(String),
 
-    #[error("xml error")]
-    XmlError,
+    #[error("{0}")]
+    AwsApiError(AwsApiError),
 
-    #[error("setting error {0}")]
+    #[error("no cached response for {0} in offline mode")]
+    CacheMissError(String),
+
+    #[error("failed to list resources of some profiles or regions ({0})")]
+    TargetError(usize),
+
+    #[error("request timed out after {0} seconds")]
+    TimeoutError(u64),
+
+    #[error("no recorded response for {0}")]
+    ReplayMissError(String),
+
+    #[error("invalid xml: {0}")]
+    InvalidXml(String),
+
+    #[error("setting error: {0}")]
     SettingError(String),
 
-    #[error("json error")]
+    #[error("json error: {0}")]
     JsonError(#[from] serde_json::error::Error),
 
-    #[error("duplicated xml tag {0}")]
-    DuplicatedXmlTag(String),
-
-    #[error("parameter error {0}")]
+    #[error("parameter error: {0}")]
     ParameterError(String),
 
-    #[error("std::io::error {0:?}")]
+    #[error("io error: {0}")]
     TermError(#[from] std::io::Error),
 
-    #[error("rustbox init error {0:?}")]
+    #[error("terminal init error: {0}")]
     RustboxError(#[from] rustbox::InitError),
 
-    #[error("url encode error {0:?}")]
+    #[error("url encode error: {0}")]
     SerdeUrlEncodeError(#[from] serde_urlencoded::ser::Error),
+
+    #[error("yaml error: {0}")]
+    SerdeYamlError(#[from] serde_yaml::Error),
+
+    #[error("cancelled")]
+    Cancelled,
+
+    #[error("no resource selected")]
+    NoSelection,
+}
+
+impl Error {
+    /// 2 for arguments, 3 for aws apis, 4 when nothing is selected and 130 when cancelled.
+    pub(crate) fn exit_code(&self) -> i32 {
+        match self {
+            Error::ArgumentError(_) | Error::ParseRegionError(_) | Error::ParameterError(_) => 2,
+            Error::AwsApiError(_)
+            | Error::RusotoError(_)
+            | Error::TimeoutError(_)
+            | Error::TargetError(_)
+            | Error::AwsCredentialsError(_)
+            | Error::TlsError(_)
+            | Error::CacheMissError(_)
+            | Error::ReplayMissError(_) => 3,
+            Error::NoSelection => 4,
+            Error::Cancelled => 130,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct AwsApiError {
+    pub(crate) code: String,
+    pub(crate) message: String,
+    pub(crate) request_id: Option<String>,
+    pub(crate) status: u16,
+    pub(crate) retry_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AwsErrorKind {
+    AccessDenied,
+    ExpiredToken,
+    UnrecognizedClient,
+    Other,
+}
+
+impl AwsApiError {
+    pub(crate) fn kind(&self) -> AwsErrorKind {
+        match &self.code[..] {
+            "AccessDenied" | "AccessDeniedException" | "UnauthorizedOperation" => {
+                AwsErrorKind::AccessDenied
+            }
+            "ExpiredToken" | "ExpiredTokenException" | "RequestExpired" => {
+                AwsErrorKind::ExpiredToken
+            }
+            "UnrecognizedClientException" | "InvalidClientTokenId" | "AuthFailure" => {
+                AwsErrorKind::UnrecognizedClient
+            }
+            _ => AwsErrorKind::Other,
+        }
+    }
+
+    fn hint(&self) -> Option<&'static str> {
+        match self.kind() {
+            AwsErrorKind::AccessDenied => Some("check the iam policy of the current profile."),
+            AwsErrorKind::ExpiredToken => Some("the session token has expired, refresh it."),
+            AwsErrorKind::UnrecognizedClient => {
+                Some("the credentials are not valid for this account or region.")
+            }
+            AwsErrorKind::Other => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AwsApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)?;
+        if let Some(hint) = self.hint() {
+            write!(f, " ({})", hint)?;
+        }
+        write!(f, " [status: {}", self.status)?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request id: {}", request_id)?;
+        }
+        if self.retry_count > 0 {
+            write!(f, ", retried: {}", self.retry_count)?;
+        }
+        write!(f, "]")
+    }
 }
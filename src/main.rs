@@ -1,28 +1,55 @@
-use error::Result;
+use error::{Error, Result};
 use structopt::StructOpt;
 
 mod api;
+mod catalog;
 mod color;
+mod completion;
 mod error;
 mod help;
 mod log;
 mod opts;
+mod output;
 mod service;
 mod show;
+mod template;
 mod ui;
 mod yaml_path;
 
 #[tokio::main]
 async fn main() {
-    match run(opts::Opts::from_args()).await {
-        Ok(_) => (),
-        Err(err) => eprintln!("{:?}", err),
+    let opts = match opts::Opts::from_iter_safe(std::env::args_os()) {
+        Ok(opts) => opts,
+        Err(err) if err.use_stderr() => {
+            eprintln!("{}", err.message);
+            std::process::exit(2);
+        }
+        // --help and --version
+        Err(err) => err.exit(),
+    };
+
+    if let Err(err) = run(opts).await {
+        match err {
+            Error::Cancelled | Error::NoSelection => (),
+            _ => eprintln!("hrkk: {}", err),
+        }
+        std::process::exit(err.exit_code());
     }
 }
 
 async fn run(opts: opts::Opts) -> Result<()> {
+    match &opts.sub_command {
+        Some(opts::SubCommand::Resources { output }) => return catalog::print(output),
+        Some(opts::SubCommand::Completions { shell }) => {
+            return completion::print_script(shell, &opts)
+        }
+        Some(opts::SubCommand::Complete { service, resource }) => {
+            return completion::print_keys(service, resource, &opts)
+        }
+        _ => (),
+    }
+
     opts.validate()?;
-    opts.set_profile();
 
     match &opts.sub_command {
         Some(sub_command) => service::execute_command(sub_command, opts.clone()).await?,
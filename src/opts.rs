@@ -12,10 +12,46 @@ pub(crate) struct Opts {
     #[structopt(short = "l", long = "list-request")]
     pub(crate) list_request_count: Option<u8>,
 
+    /// Fetch all resources by following the next token of list- or describe- api.
+    #[structopt(long = "all")]
+    pub(crate) all: bool,
+
+    /// Stop fetching resources at this count with --all or --output. default is 10000
+    #[structopt(long = "max-items")]
+    pub(crate) max_items: Option<usize>,
+
     /// Initial aws request count for get- api. Hit "G" to get more.
     #[structopt(short = "g", long = "get-request")]
     pub(crate) get_request_count: Option<u8>,
 
+    /// Max count of get- api requests in flight at the same time. default is 8
+    #[structopt(short = "c", long = "concurrency")]
+    pub(crate) concurrency: Option<u8>,
+
+    /// Max retry count for throttled or failed aws api requests. default is 5
+    #[structopt(long = "max-retries")]
+    pub(crate) max_retries: Option<u8>,
+
+    /// Timeout seconds of each aws api request, "X" cancels requests in flight. default is 30
+    #[structopt(long = "timeout")]
+    pub(crate) timeout: Option<u64>,
+
+    /// Use cached list- and get- api responses younger than this seconds. "R" reloads without cache.
+    #[structopt(long = "cache-ttl")]
+    pub(crate) cache_ttl: Option<u64>,
+
+    /// Read aws api responses only from the cache, without any request.
+    #[structopt(long = "offline")]
+    pub(crate) offline: bool,
+
+    /// Record every aws api request and its response as a json file in the directory.
+    #[structopt(long = "record")]
+    pub(crate) record: Option<String>,
+
+    /// Serve aws api responses from files recorded with --record, without credentials or network.
+    #[structopt(long = "replay")]
+    pub(crate) replay: Option<String>,
+
     /// Store aws api response as "response_body.txt" in the cache directory.
     #[structopt(short = "b", long = "debug")]
     pub(crate) debug: bool,
@@ -28,11 +64,43 @@ pub(crate) struct Opts {
     #[structopt(short = "p", long = "profile")]
     pub(crate) profile: Option<String>,
 
-    /// Aws region for the aws api request.
+    /// Comma separated profile names like "dev,stg,prod" to list resources of each account.
+    #[structopt(long = "profiles", conflicts_with_all = &["profile", "all-profiles"])]
+    pub(crate) profiles: Option<String>,
+
+    /// List resources of all profiles in ~/.aws/config and ~/.aws/credentials.
+    #[structopt(long = "all-profiles", conflicts_with = "profile")]
+    pub(crate) all_profiles: bool,
+
+    /// Role arn to assume with the credentials of the profile. Session credentials are cached until they expire.
+    #[structopt(long = "role-arn")]
+    pub(crate) role_arn: Option<String>,
+
+    /// Serial number or arn of the mfa device for --role-arn. hrkk prompts for the token code.
+    #[structopt(long = "mfa-serial", requires = "role-arn")]
+    pub(crate) mfa_serial: Option<String>,
+
+    /// Aws region for the aws api request. Comma separated regions like "us-east-1,eu-west-1" list resources of each region.
     #[structopt(short = "r", long = "region")]
     pub(crate) region: Option<String>,
 
-    /// Delimiter for the output text. default is ","
+    /// List resources of all regions enabled by default. Opt-in regions need --region.
+    #[structopt(long = "all-regions", conflicts_with = "region")]
+    pub(crate) all_regions: bool,
+
+    /// Endpoint url for all aws api requests, e.g. "http://localhost:4566" for LocalStack.
+    #[structopt(long = "endpoint-url")]
+    pub(crate) endpoint_url: Option<String>,
+
+    /// Endpoint url for a service as "<service>=<url>", e.g. "logs=http://localhost:5000". Overrides --endpoint-url.
+    #[structopt(long = "service-endpoint", number_of_values = 1)]
+    pub(crate) service_endpoints: Vec<String>,
+
+    /// Ca bundle to verify ssl certificates, like AWS_CA_BUNDLE. Proxies are read from HTTPS_PROXY, HTTP_PROXY and NO_PROXY.
+    #[structopt(long = "ca-bundle")]
+    pub(crate) ca_bundle: Option<String>,
+
+    /// Delimiter for the output text. default is ",", "\n" for a line per resource.
     #[structopt(short = "d", long = "delimiter")]
     pub(crate) delimiter: Option<String>,
 
@@ -40,9 +108,51 @@ pub(crate) struct Opts {
     #[structopt(short = "u", long = "console-url")]
     pub(crate) console_url: bool,
 
+    /// Output the value at the path like "private_ip_address" or "tag_set[?key=='Name'].value" for the selected resources.
+    /// Repeat it to output tab separated values, a line per resource.
+    #[structopt(
+        long = "query",
+        value_name = "path",
+        number_of_values = 1,
+        conflicts_with = "console-url"
+    )]
+    pub(crate) queries: Vec<String>,
+
+    /// Output the template like "{instance_id} {tag:Name} {placement.availability_zone}" for the selected resources.
+    #[structopt(long = "format", conflicts_with_all = &["console-url", "queries"])]
+    pub(crate) format: Option<String>,
+
+    /// Initial search text to filter the resources, like "api" for "logs log-group".
+    #[structopt(long = "search")]
+    pub(crate) search: Option<String>,
+
+    /// Output the resource without selecting it when only one is left by the search, like fzf --select-1.
+    #[structopt(long = "select-one")]
+    pub(crate) select_one: bool,
+
+    /// Exit without output when no resource is left by the search.
+    #[structopt(long = "exit-zero")]
+    pub(crate) exit_zero: bool,
+
+    /// Print resources as "json", "yaml", "csv" or "tsv" without the tui.
+    #[structopt(short = "o", long = "output", possible_values = &["json", "yaml", "csv", "tsv"])]
+    pub(crate) output: Option<OutputFormat>,
+
     /// Sub command.
     #[structopt(subcommand)]
     pub(crate) sub_command: Option<SubCommand>,
+
+    #[structopt(skip)]
+    #[serde(skip)]
+    pub(crate) credentials: crate::api::credentials::Store,
+
+    #[structopt(skip)]
+    #[serde(skip)]
+    pub(crate) http_clients: crate::api::http::Pool,
+
+    #[structopt(skip)]
+    #[serde(skip)]
+    pub(crate) retries: crate::api::retry::Log,
 }
 
 impl Opts {
@@ -55,6 +165,60 @@ impl Opts {
             }
         }
 
+        if let Some(0) = self.max_items {
+            return Err(ArgumentError("max-items must be 1 or more".to_string()));
+        }
+
+        if self.output.is_some() && self.sub_command.is_none() {
+            return Err(ArgumentError(
+                "output needs a sub command like \"ec2 instance\"".to_string(),
+            ));
+        }
+
+        if self.record.is_some() && self.replay.is_some() {
+            return Err(ArgumentError(
+                "record and replay can't be used together".to_string(),
+            ));
+        }
+
+        if let Some(0) = self.concurrency {
+            return Err(ArgumentError("concurrency must be 1 or more".to_string()));
+        }
+
+        if let Some(0) = self.timeout {
+            return Err(ArgumentError("timeout must be 1 or more".to_string()));
+        }
+
+        if self.profiles().is_empty() {
+            return Err(ArgumentError(
+                "no profile found in ~/.aws/config or ~/.aws/credentials".to_string(),
+            ));
+        }
+
+        if self.regions().is_empty() {
+            return Err(ArgumentError("no region is given".to_string()));
+        }
+        for region in self.regions() {
+            Region::from_str(&region)?;
+        }
+
+        for query in &self.queries {
+            crate::yaml_path::Path::parse(query)?;
+        }
+
+        if let Some(format) = &self.format {
+            crate::template::Template::parse(format)?;
+        }
+
+        for service_endpoint in &self.service_endpoints {
+            if !service_endpoint.contains('=') {
+                return Err(ArgumentError(format!(
+                    "service-endpoint must be \"<service>=<url>\": {}",
+                    service_endpoint
+                )));
+            }
+        }
+
         Ok(())
     }
 
@@ -65,6 +229,10 @@ impl Opts {
         }
     }
 
+    pub(crate) fn max_items(&self) -> usize {
+        self.max_items.unwrap_or(10000)
+    }
+
     pub(crate) fn get_request_count(&self) -> usize {
         match self.get_request_count {
             Some(count) => count as usize,
@@ -72,23 +240,199 @@ impl Opts {
         }
     }
 
+    pub(crate) fn concurrency(&self) -> usize {
+        match self.concurrency {
+            Some(count) => count as usize,
+            None => 8,
+        }
+    }
+
+    pub(crate) fn max_retries(&self) -> usize {
+        match self.max_retries {
+            Some(count) => count as usize,
+            None => 5,
+        }
+    }
+
+    pub(crate) fn ca_bundle(&self) -> Option<String> {
+        match &self.ca_bundle {
+            Some(path) => Some(path.clone()),
+            None => std::env::var("AWS_CA_BUNDLE")
+                .ok()
+                .filter(|path| !path.is_empty()),
+        }
+    }
+
+    pub(crate) fn timeout(&self) -> u64 {
+        self.timeout.unwrap_or(30)
+    }
+
     pub(crate) fn delimiter(&self) -> String {
         match &self.delimiter {
-            Some(delimiter) => delimiter.to_string(),
+            Some(delimiter) => delimiter.replace("\\n", "\n").replace("\\t", "\t"),
             None => ",".to_string(),
         }
     }
 
-    pub(crate) fn set_profile(&self) {
-        if let Some(profile) = &self.profile {
-            std::env::set_var("AWS_PROFILE", profile)
+    pub(crate) fn profile_name(&self) -> String {
+        match &self.profile {
+            Some(profile) => profile.to_string(),
+            None => std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_string()),
         }
     }
 
+    /// True when profiles are given by the options, not by AWS_PROFILE or the default.
+    pub(crate) fn is_profile_named(&self) -> bool {
+        self.profile.is_some() || self.profiles.is_some() || self.all_profiles
+    }
+
+    /// Profiles to list resources of, the first one is the profile of `profile_name()`.
+    pub(crate) fn profiles(&self) -> Vec<String> {
+        if self.all_profiles {
+            return crate::api::profile::names();
+        }
+
+        match &self.profiles {
+            Some(profiles) => profiles
+                .split(',')
+                .map(|profile| profile.trim().to_string())
+                .filter(|profile| !profile.is_empty())
+                .collect(),
+            None => vec![self.profile_name()],
+        }
+    }
+
+    /// Copy of the options for the api requests of one profile.
+    /// The implicit profile stays implicit, so environment variables still take precedence over it.
+    pub(crate) fn with_profile(&self, profile: &str) -> Opts {
+        let mut opts = self.clone();
+        if self.is_profile_named() || profile != self.profile_name() {
+            opts.profile = Some(profile.to_string());
+        }
+        opts.profiles = None;
+        opts.all_profiles = false;
+        opts
+    }
+
+    pub(crate) fn with_target(&self, target: &Target) -> Opts {
+        self.with_profile(&target.profile)
+            .with_region(&target.region)
+    }
+
+    /// Region of the requests, an error with multiple regions, whose requests use `with_target()` or `with_region()`.
     pub(crate) fn region(&self) -> Result<Region> {
-        Ok(match &self.region {
+        let regions = match &self.region {
+            Some(region) => region
+                .split(',')
+                .map(str::trim)
+                .filter(|region| !region.is_empty())
+                .collect(),
+            None => vec![],
+        };
+        if self.all_regions || regions.len() > 1 {
+            return Err(ArgumentError(
+                "a single region is needed, but multiple regions are given".to_string(),
+            ));
+        }
+        Ok(match regions.first() {
             Some(region) => Region::from_str(region)?,
-            None => Region::default(),
+            None => match self.profile_region() {
+                Some(region) => Region::from_str(&region)?,
+                None => Region::default(),
+            },
+        })
+    }
+
+    /// Region of the apis of the partition like sts and iam, the first one of the regions.
+    pub(crate) fn partition_region(&self) -> Result<Region> {
+        match self.regions().first() {
+            Some(region) => Ok(Region::from_str(region)?),
+            None => self.region(),
+        }
+    }
+
+    /// Region in ~/.aws/config of the profile given by the option, environment variables take precedence.
+    fn profile_region(&self) -> Option<String> {
+        if std::env::var("AWS_DEFAULT_REGION").is_ok() || std::env::var("AWS_REGION").is_ok() {
+            return None;
+        }
+        self.profile
+            .as_ref()
+            .and_then(|profile| crate::api::profile::region(profile))
+    }
+
+    /// Regions to list resources of.
+    pub(crate) fn regions(&self) -> Vec<String> {
+        if self.all_regions {
+            return default_regions();
+        }
+
+        match &self.region {
+            Some(region) => region
+                .split(',')
+                .map(|region| region.trim().to_string())
+                .filter(|region| !region.is_empty())
+                .collect(),
+            None => vec![self.region_name()],
+        }
+    }
+
+    /// Copy of the options for the api requests of one region.
+    pub(crate) fn with_region(&self, region: &str) -> Opts {
+        let mut opts = self.clone();
+        opts.region = Some(region.to_string());
+        opts.all_regions = false;
+        opts
+    }
+
+    pub(crate) fn profile_label(&self) -> String {
+        if self.all_profiles {
+            return "all profiles".to_string();
+        }
+
+        let profiles = self.profiles();
+        if profiles.len() > 1 {
+            format!("{} profiles", profiles.len())
+        } else {
+            self.profile_name()
+        }
+    }
+
+    pub(crate) fn region_label(&self) -> String {
+        let regions = self.regions();
+        if regions.len() > 1 {
+            format!("{} regions", regions.len())
+        } else {
+            self.region_name()
+        }
+    }
+
+    pub(crate) fn endpoint_url(&self, service_name: &str) -> Option<String> {
+        for service_endpoint in &self.service_endpoints {
+            let pair = service_endpoint.splitn(2, '=').collect::<Vec<&str>>();
+            if pair.len() == 2 && pair[0] == service_name {
+                return Some(pair[1].to_string());
+            }
+        }
+        self.endpoint_url.clone()
+    }
+
+    pub(crate) fn request_region(
+        &self,
+        service_name: &str,
+        fixed_region: &Option<Region>,
+    ) -> Result<Region> {
+        let region = match fixed_region {
+            Some(region) => region.clone(),
+            None => self.region()?,
+        };
+
+        Ok(match self.endpoint_url(service_name) {
+            Some(endpoint) => Region::Custom {
+                name: region.name().to_string(),
+                endpoint,
+            },
+            None => region,
         })
     }
 
@@ -101,14 +445,122 @@ impl Opts {
     }
 
     pub(crate) fn output_type(&self) -> OutputType {
+        let paths = self
+            .queries
+            .iter()
+            .filter_map(|query| crate::yaml_path::Path::parse(query).ok())
+            .collect::<Vec<_>>();
+        let template = self
+            .format
+            .as_ref()
+            .and_then(|format| crate::template::Template::parse(format).ok());
         if self.console_url {
             OutputType::ConsoleURL
+        } else if let Some(template) = template {
+            OutputType::Format(template)
+        } else if !paths.is_empty() {
+            OutputType::Query(paths)
         } else {
             OutputType::ResourceIdentifier
         }
     }
 }
 
+/// Profile and region of api requests, resources are listed per target.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Target {
+    pub(crate) profile: String,
+    pub(crate) region: String,
+}
+
+/// Profile and region columns to tell apart resources of several targets.
+#[derive(Debug, Clone)]
+pub(crate) struct TargetColumns {
+    profile: bool,
+    region: bool,
+}
+
+impl TargetColumns {
+    pub(crate) fn new(targets: &[Target]) -> Self {
+        TargetColumns {
+            profile: targets.iter().any(|t| t.profile != targets[0].profile),
+            region: targets.iter().any(|t| t.region != targets[0].region),
+        }
+    }
+
+    pub(crate) fn header(&self) -> Vec<&'static str> {
+        let mut header = vec![];
+        if self.profile {
+            header.push("profile");
+        }
+        if self.region {
+            header.push("region");
+        }
+        header
+    }
+
+    pub(crate) fn values(&self, target: &Target) -> Vec<String> {
+        let mut values = vec![];
+        if self.profile {
+            values.push(target.profile.clone());
+        }
+        if self.region {
+            values.push(target.region.clone());
+        }
+        values
+    }
+}
+
+/// Names of all regions including opt-in, china and gov cloud regions.
+pub(crate) fn region_names() -> Vec<String> {
+    use Region::*;
+    vec![
+        UsEast1,
+        UsEast2,
+        UsWest1,
+        UsWest2,
+        CaCentral1,
+        SaEast1,
+        EuCentral1,
+        EuWest1,
+        EuWest2,
+        EuWest3,
+        EuNorth1,
+        EuSouth1,
+        ApEast1,
+        ApNortheast1,
+        ApNortheast2,
+        ApNortheast3,
+        ApSouth1,
+        ApSoutheast1,
+        ApSoutheast2,
+        MeSouth1,
+        AfSouth1,
+        CnNorth1,
+        CnNorthwest1,
+        UsGovEast1,
+        UsGovWest1,
+    ]
+    .iter()
+    .map(|region| region.name().to_string())
+    .collect()
+}
+
+/// Regions which accounts have to enable before use.
+const OPT_IN_REGIONS: [&str; 4] = ["ap-east-1", "eu-south-1", "me-south-1", "af-south-1"];
+
+/// Regions of `region_names()` enabled by default for every account of the aws partition, for --all-regions.
+fn default_regions() -> Vec<String> {
+    region_names()
+        .into_iter()
+        .filter(|region| {
+            !OPT_IN_REGIONS.contains(&region.as_str())
+                && !region.starts_with("cn-")
+                && !region.starts_with("us-gov-")
+        })
+        .collect()
+}
+
 #[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
 pub(crate) enum SubCommand {
     /// ACM
@@ -118,6 +570,13 @@ pub(crate) enum SubCommand {
         command: Acm,
     },
 
+    /// API Gateway
+    #[structopt(name = "apigateway")]
+    Apigateway {
+        #[structopt(subcommand)]
+        command: Apigateway,
+    },
+
     /// Athena
     #[structopt(name = "athena")]
     Athena {
@@ -132,6 +591,13 @@ pub(crate) enum SubCommand {
         command: Autoscaling,
     },
 
+    /// Batch
+    #[structopt(name = "batch")]
+    Batch {
+        #[structopt(subcommand)]
+        command: Batch,
+    },
+
     /// Cloudformation
     #[structopt(name = "cloudformation")]
     Cloudformation {
@@ -160,6 +626,13 @@ pub(crate) enum SubCommand {
         command: Ec2,
     },
 
+    /// EKS
+    #[structopt(name = "eks")]
+    Eks {
+        #[structopt(subcommand)]
+        command: Eks,
+    },
+
     /// ElastiCache
     #[structopt(name = "elasticache")]
     Elasticache {
@@ -189,12 +662,19 @@ pub(crate) enum SubCommand {
     },
 
     /// Firehose Service
-    #[structopt(name = "es")]
+    #[structopt(name = "firehose")]
     Firehose {
         #[structopt(subcommand)]
         command: Firehose,
     },
 
+    /// Glacier
+    #[structopt(name = "glacier")]
+    Glacier {
+        #[structopt(subcommand)]
+        command: Glacier,
+    },
+
     /// IAM
     #[structopt(name = "iam")]
     Iam {
@@ -250,6 +730,32 @@ pub(crate) enum SubCommand {
         #[structopt(subcommand)]
         command: Ssm,
     },
+
+    /// Print the resource types with their apis, docs and console urls
+    #[structopt(name = "resources")]
+    Resources {
+        #[structopt(
+            short = "o",
+            long = "output",
+            default_value = "markdown",
+            possible_values = &["json", "yaml", "markdown"]
+        )]
+        output: String,
+    },
+
+    /// Print the completion script of the shell
+    ///
+    /// Arguments like the log group of `logs log-stream` complete with the names cached by
+    /// earlier runs with --cache-ttl, in the profile and region typed with -p and -r.
+    #[structopt(name = "completions")]
+    Completions {
+        #[structopt(possible_values = &["bash", "zsh", "fish"])]
+        shell: String,
+    },
+
+    /// Print the cached keys of the resource for completion scripts
+    #[structopt(name = "complete", setting = structopt::clap::AppSettings::Hidden)]
+    Complete { service: String, resource: String },
 }
 
 #[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
@@ -268,6 +774,12 @@ pub(crate) enum Ec2 {
     Vpc,
 }
 
+#[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
+pub(crate) enum Eks {
+    #[structopt(name = "cluster")]
+    Cluster,
+}
+
 #[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
 pub(crate) enum Elasticache {
     #[structopt(name = "cache-cluster")]
@@ -298,6 +810,12 @@ pub(crate) enum Firehose {
     DeliveryStream,
 }
 
+#[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
+pub(crate) enum Glacier {
+    #[structopt(name = "vault")]
+    Vault,
+}
+
 #[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
 pub(crate) enum Iam {
     #[structopt(name = "user")]
@@ -333,6 +851,11 @@ pub(crate) enum Logs {
 pub(crate) enum Lambda {
     #[structopt(name = "function")]
     Function,
+    #[structopt(name = "alias")]
+    Alias {
+        /// function name
+        function_name: Option<String>,
+    },
 }
 
 #[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
@@ -345,6 +868,11 @@ pub(crate) enum RdsCommand {
 pub(crate) enum S3Command {
     #[structopt(name = "bucket")]
     Bucket,
+    #[structopt(name = "object")]
+    Object {
+        /// bucket name
+        bucket: Option<String>,
+    },
 }
 
 #[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
@@ -360,6 +888,12 @@ pub(crate) enum Ssm {
     },
 }
 
+#[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
+pub(crate) enum Batch {
+    #[structopt(name = "job-queue")]
+    JobQueue,
+}
+
 #[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
 pub(crate) enum Cloudformation {
     #[structopt(name = "stack")]
@@ -390,6 +924,12 @@ pub(crate) enum Acm {
     Certificate,
 }
 
+#[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
+pub(crate) enum Apigateway {
+    #[structopt(name = "rest-api")]
+    RestApi,
+}
+
 #[derive(StructOpt, Debug, PartialEq, Clone, Serialize)]
 pub(crate) enum Athena {
     #[structopt(name = "query-execution")]
@@ -413,7 +953,32 @@ pub(crate) enum Route53 {
     },
 }
 
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub(crate) enum OutputFormat {
+    Json,
+    Yaml,
+    Csv,
+    Tsv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(OutputFormat::Json),
+            "yaml" => Ok(OutputFormat::Yaml),
+            "csv" => Ok(OutputFormat::Csv),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(format!("unknown output format: {}", s)),
+        }
+    }
+}
+
 pub(crate) enum OutputType {
     ResourceIdentifier,
     ConsoleURL,
+    /// Values at the paths in the list yaml, or in the get yaml if the list yaml doesn't have them.
+    Query(Vec<crate::yaml_path::Path>),
+    Format(crate::template::Template),
 }
@@ -0,0 +1,162 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use crate::opts::{Opts, OutputFormat, TargetColumns};
+use crate::service::{AwsResource, ResourceList};
+use futures::future::join_all;
+use serde_json::{Map, Value};
+use yaml_rust::Yaml;
+
+/// Print resources without the tui, listing every profile and region concurrently up to max items.
+pub(crate) async fn print(
+    resource: &dyn AwsResource,
+    parameter: &Option<String>,
+    opts: &Opts,
+    format: &OutputFormat,
+) -> Result<()> {
+    assume_roles(opts).await?;
+
+    let targets = resource.targets(opts);
+    let columns = TargetColumns::new(&targets);
+    let multi_target = targets.len() > 1;
+
+    let mut list = vec![];
+    let mut failed = 0;
+    for (target, result) in join_all(targets.into_iter().map(|target| async move {
+        let opts = if multi_target {
+            opts.with_target(&target)
+        } else {
+            opts.clone()
+        };
+        let result = fetch(resource, parameter, &opts).await;
+        (target, result)
+    }))
+    .await
+    {
+        let resources = match result {
+            Ok(resources) => resources,
+            Err(e) => {
+                eprintln!("hrkk: {} {}: {}", target.profile, target.region, e);
+                failed += 1;
+                continue;
+            }
+        };
+        for (mut line, mut yaml) in resources {
+            if let Yaml::Hash(hash) = &mut yaml {
+                for (key, value) in columns.header().iter().zip(columns.values(&target)) {
+                    hash.insert(Yaml::String(key.to_string()), Yaml::String(value));
+                }
+            }
+            line.append(&mut columns.values(&target));
+            list.push((line, yaml));
+        }
+    }
+    list.truncate(opts.max_items());
+
+    let mut header = resource.info().header.clone();
+    header.append(&mut columns.header());
+    let rows = list
+        .iter()
+        .map(|(line, _)| line.clone())
+        .collect::<Vec<_>>();
+    let yaml = Yaml::Array(list.into_iter().map(|(_, yaml)| yaml).collect());
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&to_json(&yaml))?),
+        OutputFormat::Yaml => {
+            let mut out_str = String::new();
+            yaml_rust::YamlEmitter::new(&mut out_str).dump(&yaml)?;
+            println!("{}", out_str);
+        }
+        OutputFormat::Csv => {
+            println!("{}", csv_line(&header));
+            for row in &rows {
+                println!("{}", csv_line(row));
+            }
+        }
+        OutputFormat::Tsv => {
+            println!("{}", header.join("\t"));
+            for row in &rows {
+                println!("{}", row.join("\t"));
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(TargetError(failed)),
+    }
+}
+
+/// Read mfa token codes of the roles from stdin.
+async fn assume_roles(opts: &Opts) -> Result<()> {
+    for profile in opts.profiles() {
+        let opts = opts.with_profile(&profile);
+        if let Some(serial) = crate::api::credentials::mfa_serial_to_prompt(&opts) {
+            eprint!("mfa token code for {}: ", serial);
+            let mut token = String::new();
+            std::io::stdin().read_line(&mut token)?;
+            crate::api::credentials::assume_role_with_token(&opts, token.trim()).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn fetch(
+    resource: &dyn AwsResource,
+    parameter: &Option<String>,
+    opts: &Opts,
+) -> Result<ResourceList> {
+    let mut list = vec![];
+    let mut next_token = None;
+    loop {
+        let (mut page, token, _) =
+            crate::api::list::call(resource, parameter, opts, next_token, true).await?;
+        list.append(&mut page);
+        next_token = token;
+        if next_token.is_none() || opts.max_items() <= list.len() {
+            return Ok(list);
+        }
+    }
+}
+
+pub(crate) fn requires_parameter(resource: &dyn AwsResource) -> crate::error::Error {
+    ArgumentError(format!(
+        "{} needs a parameter to print without the tui.",
+        resource.resource_full_name()
+    ))
+}
+
+fn csv_line<S: AsRef<str>>(cols: &[S]) -> String {
+    cols.iter()
+        .map(|col| {
+            let col = col.as_ref();
+            if col.contains(&[',', '"', '\n'][..]) {
+                format!("\"{}\"", col.replace("\"", "\"\""))
+            } else {
+                col.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+pub(crate) fn to_json(yaml: &Yaml) -> Value {
+    match yaml {
+        Yaml::String(string) => Value::String(string.clone()),
+        Yaml::Integer(int) => Value::from(*int),
+        Yaml::Real(real) => match real.parse::<f64>() {
+            Ok(real) => Value::from(real),
+            Err(_) => Value::String(real.clone()),
+        },
+        Yaml::Boolean(bool) => Value::Bool(*bool),
+        Yaml::Array(array) => Value::Array(array.iter().map(to_json).collect()),
+        Yaml::Hash(hash) => {
+            let mut map = Map::new();
+            for (key, value) in hash {
+                map.insert(crate::show::raw(key), to_json(value));
+            }
+            Value::Object(map)
+        }
+        Yaml::Alias(_) | Yaml::Null | Yaml::BadValue => Value::Null,
+    }
+}
@@ -17,9 +17,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["domain name", "arn"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "CertificateManager.ListCertificates",
-                    },
+                    target: "CertificateManager.ListCertificates",
                     service_name: "acm",
                     json: json!({}),
                     limit: Some(Limit {
@@ -32,7 +30,7 @@ pub(crate) fn new() -> Resource {
                 document: DocumentUrl("acm/latest/APIReference/API_ListCertificates.html"),
             },
             get_api: Some(GetApi {
-                param_path: vec!["certificate_arn"],
+                param_path: Path::new("certificate_arn"),
                 format: GetFormat::Json(GetJson {
                     method: Method::Post,
                     path: ("/", None),
@@ -68,14 +66,14 @@ impl AwsResource for Resource {
             Some(get_yaml) => {
                 let merged = merge_yamls(list, get_yaml);
                 Section::new(&merged)
-                    .yaml_name_n(&["list", "domain_name"])
+                    .yaml_name_n("list.domain_name")
                     .resource_url(self.console_url(list, get, region))
-                    .raw_n("arn", &["list", "certificate_arn"])
-                    .raw_n("certificate", &["get", "certificate"])
-                    .raw_n("certificate chain", &["get", "certificate_chain"])
+                    .raw_n("arn", "list.certificate_arn")
+                    .raw_n("certificate", "get.certificate")
+                    .raw_n("certificate chain", "get.certificate_chain")
             }
             _ => Section::new(list)
-                .yaml_name_n(&["list", "domain_name"])
+                .yaml_name_n("list.domain_name")
                 .resource_url(self.console_url(list, get, region)),
         }
     }
@@ -0,0 +1,71 @@
+use crate::service::prelude::*;
+
+#[derive(Serialize)]
+pub(crate) struct Resource {
+    info: Info,
+}
+
+pub(crate) fn new() -> Resource {
+    Resource {
+        info: Info {
+            sub_command: Some(SubCommand::Apigateway {
+                command: Apigateway::RestApi,
+            }),
+            key_attribute: Some("id"),
+            service_name: "apigateway",
+            resource_type_name: "rest_api",
+            header: vec!["id", "name"],
+            list_api: ListApi {
+                format: ListFormat::RestJson(ListRest {
+                    method: Method::Get,
+                    path: "/restapis",
+                    service_name: "apigateway",
+                    params: vec![],
+                    limit: Some((Location::Query("limit"), 500)),
+                    token: Some(Location::Query("position")),
+                    parameter: None,
+                    iteration_tag: vec![],
+                    region: None,
+                }),
+                document: DocumentUrl("apigateway/api-reference/link-relation/restapi-restapis/"),
+            },
+            get_api: None,
+            resource_url: Some(Regional("apigateway/home?#/apis/{id}/resources")),
+        },
+    }
+}
+
+impl AwsResource for Resource {
+    fn info(&self) -> &Info {
+        &self.info
+    }
+
+    fn list_and_next_token(&self, yaml: &Yaml) -> (ResourceList, Option<String>) {
+        (
+            make_resource_list(self, &yaml["item"]),
+            next_token(yaml, Some("position")),
+        )
+    }
+
+    fn line(&self, list: &Yaml, _get: &Option<Yaml>) -> Vec<String> {
+        vec![raw(&list["id"]), raw(&list["name"])]
+    }
+
+    fn detail(&self, list: &Yaml, get: &Option<Yaml>, region: &str) -> Section {
+        Section::new(list)
+            .yaml_name("name")
+            .resource_url(self.console_url(list, get, region))
+            .raw("id")
+            .raw("description")
+            .raw("api_key_source")
+            .section(
+                Section::new(&list["endpoint_configuration"])
+                    .string_name("endpoint configuration")
+                    .yaml_array("types", "types"),
+            )
+    }
+
+    fn url_params(&self, list: &Yaml, _get: &Option<Yaml>) -> Option<Vec<ParamSet>> {
+        Some(vec![("id", raw(&list["id"]), false)])
+    }
+}
@@ -17,9 +17,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["query execution id", "state", "completion time"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "AmazonAthena.ListQueryExecutions",
-                    },
+                    target: "AmazonAthena.ListQueryExecutions",
                     service_name: "athena",
                     json: json!({}),
                     limit: Some(Limit {
@@ -32,7 +30,7 @@ pub(crate) fn new() -> Resource {
                 document: DocumentUrl("athena/latest/APIReference/API_ListQueryExecutions.html"),
             },
             get_api: Some(GetApi {
-                param_path: vec![],
+                param_path: Path::new(""),
                 format: GetFormat::Json(GetJson {
                     method: Method::Post,
                     path: ("/", None),
@@ -74,29 +72,25 @@ impl AwsResource for Resource {
         match get {
             None => Section::new(list),
             Some(yaml) => Section::new(&yaml)
-                .yaml_name_n(&["query_execution", "query_execution_id"])
+                .yaml_name_n("query_execution.query_execution_id")
                 .resource_url(self.console_url(list, get, region))
-                .raw_n("query", &["query_execution", "query"])
+                .raw_n("query", "query_execution.query")
                 .raw_n(
                     "output",
-                    &["query_execution", "result_configuration", "output_location"],
+                    "query_execution.result_configuration.output_location",
                 )
-                .raw_n("state", &["query_execution", "status", "state"])
+                .raw_n("state", "query_execution.status.state")
                 .time_n(
                     "completion time",
-                    &["query_execution", "status", "completion_date_time"],
+                    "query_execution.status.completion_date_time",
                 )
                 .byte_n(
                     "data scanned",
-                    &["query_execution", "statistics", "data_scanned_in_bytes"],
+                    "query_execution.statistics.data_scanned_in_bytes",
                 )
                 .milli_sec_n(
                     "execution sec",
-                    &[
-                        "query_execution_detail",
-                        "stats",
-                        "engine_execution_time_in_millis",
-                    ],
+                    "query_execution_detail.stats.engine_execution_time_in_millis",
                 ),
         }
     }
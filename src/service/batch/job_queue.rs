@@ -0,0 +1,67 @@
+use crate::service::prelude::*;
+
+#[derive(Serialize)]
+pub(crate) struct Resource {
+    info: Info,
+}
+
+pub(crate) fn new() -> Resource {
+    Resource {
+        info: Info {
+            sub_command: Some(SubCommand::Batch {
+                command: Batch::JobQueue,
+            }),
+            key_attribute: Some("job_queue_name"),
+            service_name: "batch",
+            resource_type_name: "job_queue",
+            header: vec!["name", "state", "status"],
+            list_api: ListApi {
+                format: ListFormat::RestJson(ListRest {
+                    method: Method::Post,
+                    path: "/v1/describejobqueues",
+                    service_name: "batch",
+                    params: vec![],
+                    limit: Some((Location::Body("maxResults"), 100)),
+                    token: Some(Location::Body("nextToken")),
+                    parameter: None,
+                    iteration_tag: vec![],
+                    region: None,
+                }),
+                document: DocumentUrl("batch/latest/APIReference/API_DescribeJobQueues.html"),
+            },
+            get_api: None,
+            resource_url: None,
+        },
+    }
+}
+
+impl AwsResource for Resource {
+    fn info(&self) -> &Info {
+        &self.info
+    }
+
+    fn list_and_next_token(&self, yaml: &Yaml) -> (ResourceList, Option<String>) {
+        (
+            make_resource_list(self, &yaml["job_queues"]),
+            next_token(yaml, Some("next_token")),
+        )
+    }
+
+    fn line(&self, list: &Yaml, _get: &Option<Yaml>) -> Vec<String> {
+        vec![
+            raw(&list["job_queue_name"]),
+            raw(&list["state"]),
+            raw(&list["status"]),
+        ]
+    }
+
+    fn detail(&self, list: &Yaml, _get: &Option<Yaml>, _region: &str) -> Section {
+        Section::new(list)
+            .yaml_name("job_queue_name")
+            .raw("job_queue_arn")
+            .raw("state")
+            .raw("status")
+            .raw("status_reason")
+            .raw("priority")
+    }
+}
@@ -0,0 +1 @@
+pub(crate) mod job_queue;
@@ -31,7 +31,7 @@ pub(crate) fn new() -> Resource {
                 ),
             },
             get_api: Some(GetApi {
-                param_path: vec!["dashboard_name"],
+                param_path: Path::new("dashboard_name"),
                 format: GetFormat::Xml(GetXml {
                     service_name: "monitoring",
                     action: "GetDashboard",
@@ -81,11 +81,11 @@ impl AwsResource for Resource {
             Some(get_yaml) => {
                 let merged = merge_yamls(list, get_yaml);
                 Section::new(&merged)
-                    .yaml_name_n(&["list", "dashboard_name"])
+                    .yaml_name_n("list.dashboard_name")
                     .resource_url(self.console_url(list, get, region))
-                    .byte_n("size", &["list", "size"])
-                    .time_n("last modified", &["list", "last_modified"])
-                    .raw_n("body", &["get", "get_dashboard_result", "dashboard_body"])
+                    .byte_n("size", "list.size")
+                    .time_n("last modified", "list.last_modified")
+                    .raw_n("body", "get.get_dashboard_result.dashboard_body")
             }
             None => Section::new(list)
                 .yaml_name("dashboard_name")
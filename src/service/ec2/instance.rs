@@ -73,7 +73,7 @@ impl AwsResource for Resource {
             .raw("instance_id")
             .raw("instance_type")
             .raw("architecture")
-            .raw_n("state", &["instance_state", "name"])
+            .raw_n("state", "instance_state.name")
             .section(
                 Section::new(list)
                     .string_name("tags")
@@ -84,7 +84,7 @@ impl AwsResource for Resource {
                     .string_name("network")
                     .raw("subnet_id")
                     .raw("private_ip_address")
-                    .raw_n("availability zone", &["placement", "availability_zone"]),
+                    .raw_n("availability zone", "placement.availability_zone"),
             )
             .section(
                 Section::new(list)
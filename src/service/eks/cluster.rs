@@ -0,0 +1,91 @@
+use crate::service::prelude::*;
+
+#[derive(Serialize)]
+pub(crate) struct Resource {
+    info: Info,
+}
+
+pub(crate) fn new() -> Resource {
+    Resource {
+        info: Info {
+            sub_command: Some(SubCommand::Eks {
+                command: Eks::Cluster,
+            }),
+            key_attribute: None,
+            service_name: "eks",
+            resource_type_name: "cluster",
+            header: vec!["name", "version", "status"],
+            list_api: ListApi {
+                format: ListFormat::RestJson(ListRest {
+                    method: Method::Get,
+                    path: "/clusters",
+                    service_name: "eks",
+                    params: vec![],
+                    limit: Some((Location::Query("maxResults"), 100)),
+                    token: Some(Location::Query("nextToken")),
+                    parameter: None,
+                    iteration_tag: vec![],
+                    region: None,
+                }),
+                document: DocumentUrl("eks/latest/APIReference/API_ListClusters.html"),
+            },
+            get_api: Some(GetApi {
+                param_path: Path::new(""),
+                format: GetFormat::RestJson(GetRest {
+                    method: Method::Get,
+                    path: "/clusters/{name}",
+                    service_name: "eks",
+                    params: vec![],
+                    parameter: Location::Path("name"),
+                    iteration_tag: vec![],
+                    region: None,
+                }),
+                document: DocumentUrl("eks/latest/APIReference/API_DescribeCluster.html"),
+            }),
+            resource_url: Some(Regional("eks/home?#/clusters/{name}")),
+        },
+    }
+}
+
+impl AwsResource for Resource {
+    fn info(&self) -> &Info {
+        &self.info
+    }
+
+    fn list_and_next_token(&self, yaml: &Yaml) -> (ResourceList, Option<String>) {
+        (
+            make_resource_list(self, &yaml["clusters"]),
+            next_token(yaml, Some("next_token")),
+        )
+    }
+
+    fn line(&self, list: &Yaml, get: &Option<Yaml>) -> Vec<String> {
+        match get {
+            Some(get) => vec![
+                raw(list),
+                raw(&get["cluster"]["version"]),
+                raw(&get["cluster"]["status"]),
+            ],
+            None => vec![raw(list), "-".to_string(), "-".to_string()],
+        }
+    }
+
+    fn detail(&self, list: &Yaml, get: &Option<Yaml>, region: &str) -> Section {
+        match get {
+            None => Section::new(list),
+            Some(yaml) => Section::new(&yaml["cluster"])
+                .resource_url(self.console_url(list, get, region))
+                .yaml_name("name")
+                .raw("arn")
+                .raw("version")
+                .raw("status")
+                .raw("endpoint")
+                .raw("role_arn")
+                .time("created_at"),
+        }
+    }
+
+    fn url_params(&self, list: &Yaml, _get: &Option<Yaml>) -> Option<Vec<ParamSet>> {
+        Some(vec![("name", raw(list), true)])
+    }
+}
@@ -16,15 +16,16 @@ pub(crate) fn new() -> Resource {
             resource_type_name: "pipeline",
             header: vec!["id", "name"],
             list_api: ListApi {
-                format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Get {
-                        path: "/2012-09-25/pipelines",
-                    },
+                format: ListFormat::RestJson(ListRest {
+                    method: Method::Get,
+                    path: "/2012-09-25/pipelines",
                     service_name: "elastictranscoder",
-                    json: json!({}),
+                    params: vec![],
                     limit: None,
-                    token_name: Some("PageToken"),
-                    parameter_name: None,
+                    token: Some(Location::Query("PageToken")),
+                    parameter: None,
+                    iteration_tag: vec![],
+                    region: None,
                 }),
                 document: DocumentUrl(
                     "elastictranscoder/latest/developerguide/list-pipelines.html",
@@ -54,7 +55,7 @@ impl AwsResource for Resource {
 
     fn detail(&self, list: &Yaml, get: &Option<Yaml>, region: &str) -> Section {
         Section::new(list)
-            .yaml_name_n(&["name"])
+            .yaml_name_n("name")
             .resource_url(self.console_url(list, get, region))
             .raw("id")
             .raw("arn")
@@ -14,26 +14,29 @@ pub(crate) fn new() -> Resource {
             resource_type_name: "domain",
             header: vec!["name", "version"],
             list_api: ListApi {
-                format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Get {
-                        path: "/2015-01-01/domain",
-                    },
+                format: ListFormat::RestJson(ListRest {
+                    method: Method::Get,
+                    path: "/2015-01-01/domain",
                     service_name: "es",
-                    json: json!({}),
+                    params: vec![],
                     limit: None,
-                    token_name: None,
-                    parameter_name: None,
+                    token: None,
+                    parameter: None,
+                    iteration_tag: vec![],
+                    region: None,
                 }),
                 document: DocumentUrl("elasticsearch-service/latest/developerguide/es-configuration-api.html#es-configuration-api-actions-listdomainnames"),
             },
             get_api: Some(GetApi {
-                param_path: vec!["domain_name"],
-                format: GetFormat::Json(GetJson {
+                param_path: Path::new("domain_name"),
+                format: GetFormat::RestJson(GetRest {
                     method: Method::Get,
-                    path: ("/2015-01-01/es/domain/{domain_name}", Some("domain_name")),
+                    path: "/2015-01-01/es/domain/{DomainName}",
                     service_name: "es",
-                    target: None,
-                    parameter_name: None,
+                    params: vec![],
+                    parameter: Location::Path("DomainName"),
+                    iteration_tag: vec![],
+                    region: None,
                 }),
                 document: DocumentUrl("elasticsearch-service/latest/developerguide/es-configuration-api.html#es-configuration-api-actions-describeelasticsearchdomain"),
             }),
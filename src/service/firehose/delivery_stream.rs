@@ -17,9 +17,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["name", "create timestamp"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "Firehose_20150804.ListDeliveryStreams",
-                    },
+                    target: "Firehose_20150804.ListDeliveryStreams",
                     service_name: "firehose",
                     json: json!({}),
                     limit: Some(Limit {
@@ -32,7 +30,7 @@ pub(crate) fn new() -> Resource {
                 document: DocumentUrl("firehose/latest/APIReference/API_ListDeliveryStreams.html"),
             },
             get_api: Some(GetApi {
-                param_path: vec![],
+                param_path: Path::new(""),
                 format: GetFormat::Json(GetJson {
                     method: Method::Post,
                     path: ("/", None),
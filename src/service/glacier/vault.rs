@@ -0,0 +1,74 @@
+use crate::service::prelude::*;
+
+#[derive(Serialize)]
+pub(crate) struct Resource {
+    info: Info,
+}
+
+pub(crate) fn new() -> Resource {
+    Resource {
+        info: Info {
+            sub_command: Some(SubCommand::Glacier {
+                command: Glacier::Vault,
+            }),
+            key_attribute: Some("vault_name"),
+            service_name: "glacier",
+            resource_type_name: "vault",
+            header: vec!["name", "archives", "size"],
+            list_api: ListApi {
+                format: ListFormat::RestJson(ListRest {
+                    method: Method::Get,
+                    path: "/{accountId}/vaults",
+                    service_name: "glacier",
+                    params: vec![
+                        (Location::Path("accountId"), "-"),
+                        (Location::Header("x-amz-glacier-version"), "2012-06-01"),
+                    ],
+                    limit: Some((Location::Query("limit"), 1000)),
+                    token: Some(Location::Query("marker")),
+                    parameter: None,
+                    iteration_tag: vec![],
+                    region: None,
+                }),
+                document: DocumentUrl("amazonglacier/latest/dev/api-vaults-get.html"),
+            },
+            get_api: None,
+            resource_url: Some(Regional("glacier/home?#/vault/{vault_name}/view/details")),
+        },
+    }
+}
+
+impl AwsResource for Resource {
+    fn info(&self) -> &Info {
+        &self.info
+    }
+
+    fn list_and_next_token(&self, yaml: &Yaml) -> (ResourceList, Option<String>) {
+        (
+            make_resource_list(self, &yaml["vault_list"]),
+            next_token(yaml, Some("marker")),
+        )
+    }
+
+    fn line(&self, list: &Yaml, _get: &Option<Yaml>) -> Vec<String> {
+        vec![
+            raw(&list["vault_name"]),
+            raw(&list["number_of_archives"]),
+            byte(&list["size_in_bytes"]),
+        ]
+    }
+
+    fn detail(&self, list: &Yaml, get: &Option<Yaml>, region: &str) -> Section {
+        Section::new(list)
+            .yaml_name("vault_name")
+            .resource_url(self.console_url(list, get, region))
+            .raw("vault_arn")
+            .time("creation_date")
+            .raw("number_of_archives")
+            .byte("size_in_bytes")
+    }
+
+    fn url_params(&self, list: &Yaml, _get: &Option<Yaml>) -> Option<Vec<ParamSet>> {
+        Some(vec![("vault_name", raw(&list["vault_name"]), true)])
+    }
+}
@@ -17,9 +17,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["name", "creation timestamp"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "Kinesis_20131202.ListStreams",
-                    },
+                    target: "Kinesis_20131202.ListStreams",
                     service_name: "kinesis",
                     json: json!({}),
                     limit: Some(Limit {
@@ -32,7 +30,7 @@ pub(crate) fn new() -> Resource {
                 document: DocumentUrl("kinesis/latest/APIReference/API_ListStreams.html"),
             },
             get_api: Some(GetApi {
-                param_path: vec![],
+                param_path: Path::new(""),
                 format: GetFormat::Json(GetJson {
                     method: Method::Post,
                     path: ("/", None),
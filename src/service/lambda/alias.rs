@@ -0,0 +1,81 @@
+use crate::service::prelude::*;
+
+#[derive(Serialize)]
+pub(crate) struct Resource {
+    info: Info,
+}
+
+pub(crate) fn new() -> Resource {
+    Resource {
+        info: Info {
+            sub_command: None,
+            key_attribute: Some("name"),
+            service_name: "lambda",
+            resource_type_name: "alias",
+            header: vec!["name", "version"],
+            list_api: ListApi {
+                format: ListFormat::RestJson(ListRest {
+                    method: Method::Get,
+                    path: "/2015-03-31/functions/{FunctionName}/aliases",
+                    service_name: "lambda",
+                    params: vec![],
+                    limit: Some((Location::Query("MaxItems"), 50)),
+                    token: Some(Location::Query("Marker")),
+                    parameter: Some(Location::Path("FunctionName")),
+                    iteration_tag: vec![],
+                    region: None,
+                }),
+                document: DocumentUrl("lambda/latest/dg/API_ListAliases.html"),
+            },
+            get_api: None,
+            resource_url: None,
+        },
+    }
+}
+
+impl AwsResource for Resource {
+    fn info(&self) -> &Info {
+        &self.info
+    }
+
+    fn take_command(&self, sub_command: &SubCommand, opts: &Opts) -> Result<ExecuteTarget> {
+        if let SubCommand::Lambda {
+            command: Lambda::Alias { function_name },
+        } = sub_command
+        {
+            match function_name {
+                Some(name) => Ok(ExecuteTarget::ExecuteThis {
+                    parameter: Some(name.clone()),
+                }),
+                None => Ok(self.without_param(opts)),
+            }
+        } else {
+            Ok(ExecuteTarget::Null)
+        }
+    }
+
+    fn without_param(&self, _opts: &Opts) -> ExecuteTarget {
+        ExecuteTarget::ParameterFromResource {
+            param_resource: resource_by_name("lambda_function"),
+        }
+    }
+
+    fn list_and_next_token(&self, yaml: &Yaml) -> (ResourceList, Option<String>) {
+        (
+            make_resource_list(self, &yaml["aliases"]),
+            next_token(yaml, Some("next_marker")),
+        )
+    }
+
+    fn line(&self, list: &Yaml, _get: &Option<Yaml>) -> Vec<String> {
+        vec![raw(&list["name"]), raw(&list["function_version"])]
+    }
+
+    fn detail(&self, list: &Yaml, _get: &Option<Yaml>, _region: &str) -> Section {
+        Section::new(list)
+            .yaml_name("name")
+            .raw("alias_arn")
+            .raw("function_version")
+            .raw("description")
+    }
+}
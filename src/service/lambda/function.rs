@@ -16,18 +16,16 @@ pub(crate) fn new() -> Resource {
             resource_type_name: "function",
             header: vec!["name", "runtime"],
             list_api: ListApi {
-                format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Get {
-                        path: "/2015-03-31/functions/",
-                    },
+                format: ListFormat::RestJson(ListRest {
+                    method: Method::Get,
+                    path: "/2015-03-31/functions/",
                     service_name: "lambda",
-                    json: json!({ "descending": Some(true) }),
-                    limit: Some(Limit {
-                        name: "MaxItems",
-                        max: 10000,
-                    }),
-                    token_name: Some("Marker"),
-                    parameter_name: None,
+                    params: vec![],
+                    limit: Some((Location::Query("MaxItems"), 50)),
+                    token: Some(Location::Query("Marker")),
+                    parameter: None,
+                    iteration_tag: vec![],
+                    region: None,
                 }),
                 document: DocumentUrl("lambda/latest/dg/API_ListFunctions.html"),
             },
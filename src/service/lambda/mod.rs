@@ -1 +1,2 @@
+pub(crate) mod alias;
 pub(crate) mod function;
@@ -17,9 +17,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["name"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "Logs_20140328.DescribeLogGroups",
-                    },
+                    target: "Logs_20140328.DescribeLogGroups",
                     service_name: "logs",
                     json: json!({}),
                     limit: Some(Limit {
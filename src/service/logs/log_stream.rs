@@ -15,9 +15,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["time", "name"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "Logs_20140328.DescribeLogStreams",
-                    },
+                    target: "Logs_20140328.DescribeLogStreams",
                     service_name: "logs",
                     json: json!({ "descending": Some(true), "orderBy": Some("LastEventTime".to_owned()) }),
                     limit: Some(Limit { name: "limit", max: 50 }),
@@ -1,23 +1,28 @@
 use crate::error::Error::*;
 use crate::error::Result;
-use crate::opts::{Opts, SubCommand};
+use crate::opts::{Opts, SubCommand, Target};
+use crate::yaml_path::Path;
 use linked_hash_map::LinkedHashMap;
 use serde::Serialize;
 use serde_json::{Map, Value};
 use yaml_rust::Yaml;
 
 pub(crate) mod acm;
+pub(crate) mod apigateway;
 pub(crate) mod athena;
 pub(crate) mod autoscaling;
+pub(crate) mod batch;
 pub(crate) mod cloudformation;
 pub(crate) mod cloudfront;
 pub(crate) mod cloudwatch;
 pub(crate) mod ec2;
+pub(crate) mod eks;
 pub(crate) mod elasticache;
 pub(crate) mod elastictranscoder;
 pub(crate) mod elb;
 pub(crate) mod es;
 pub(crate) mod firehose;
+pub(crate) mod glacier;
 pub(crate) mod iam;
 pub(crate) mod kinesis;
 pub(crate) mod lambda;
@@ -33,8 +38,10 @@ pub(crate) type ResourceList = Vec<(Vec<String>, Yaml)>;
 pub(crate) fn all_resources() -> Vec<Box<dyn AwsResource>> {
     vec![
         Box::new(acm::certificate::new()),
+        Box::new(apigateway::rest_api::new()),
         Box::new(athena::query_execution::new()),
         Box::new(autoscaling::auto_scaling_group::new()),
+        Box::new(batch::job_queue::new()),
         Box::new(cloudformation::stack::new()),
         Box::new(cloudfront::distribution::new()),
         Box::new(cloudwatch::alarm::new()),
@@ -47,17 +54,20 @@ pub(crate) fn all_resources() -> Vec<Box<dyn AwsResource>> {
         Box::new(ec2::security_group::new()),
         Box::new(ec2::subnet::new()),
         Box::new(ec2::vpc::new()),
+        Box::new(eks::cluster::new()),
         Box::new(elasticache::cache_cluster::new()),
         Box::new(elastictranscoder::pipeline::new()),
         Box::new(elb::load_balancer::new()),
         Box::new(es::domain::new()),
         Box::new(firehose::delivery_stream::new()),
+        Box::new(glacier::vault::new()),
         Box::new(iam::group::new()),
         Box::new(iam::mfa_device::new()),
         Box::new(iam::policy::new()),
         Box::new(iam::role::new()),
         Box::new(iam::user::new()),
         Box::new(kinesis::stream::new()),
+        Box::new(lambda::alias::new()),
         Box::new(lambda::function::new()),
         Box::new(logs::log_group::new()),
         Box::new(logs::log_stream::new()),
@@ -65,6 +75,7 @@ pub(crate) fn all_resources() -> Vec<Box<dyn AwsResource>> {
         Box::new(route53::hosted_zone::new()),
         Box::new(route53::resource_record_set::new()),
         Box::new(s3::bucket::new()),
+        Box::new(s3::object::new()),
         Box::new(ssm::automation_execution::new()),
         Box::new(ssm::document::new()),
         Box::new(ssm::session::new()),
@@ -83,7 +94,7 @@ pub(crate) fn resource_by_name(name: &str) -> Box<dyn AwsResource> {
 #[derive(Serialize)]
 pub(crate) struct Info {
     sub_command: Option<SubCommand>,
-    key_attribute: Option<&'static str>,
+    pub(crate) key_attribute: Option<&'static str>,
     service_name: &'static str,
     resource_type_name: &'static str,
     pub(crate) header: Vec<&'static str>,
@@ -98,6 +109,18 @@ pub(crate) enum ResourceUrl {
     Global(&'static str),
 }
 
+impl ResourceUrl {
+    /// Console url with `{...}` placeholders of the resource still in it.
+    pub(crate) fn url(&self, region: &str) -> String {
+        match self {
+            ResourceUrl::Regional(url) => {
+                format!("https://{}.console.aws.amazon.com/{}", region, url)
+            }
+            ResourceUrl::Global(url) => format!("https://console.aws.amazon.com/{}", url),
+        }
+    }
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub(crate) struct DocumentUrl(&'static str);
 
@@ -117,20 +140,21 @@ pub(crate) struct ListApi {
 pub(crate) enum ListFormat {
     Xml(ListXml),
     Json(ListJson),
+    RestJson(ListRest),
+    RestXml(ListRest),
 }
 
 impl ListFormat {
     pub(crate) fn name(&self) -> String {
         match self {
-            ListFormat::Xml(api) => format!("{} {:?}", api.path.0, api.params),
-            ListFormat::Json(ListJson {
-                method: JsonListMethod::Post { target },
-                ..
-            }) => target.to_string(),
-            ListFormat::Json(ListJson {
-                method: JsonListMethod::Get { path },
-                ..
-            }) => path.to_string(),
+            ListFormat::Xml(api) => match api.params.iter().find(|(name, _)| *name == "Action") {
+                Some((_, action)) => action.to_string(),
+                None => format!("{} {}", api.method.to_str(), api.path.0),
+            },
+            ListFormat::Json(api) => api.target.to_string(),
+            ListFormat::RestJson(api) | ListFormat::RestXml(api) => {
+                format!("{} {}", api.method.to_str(), api.path)
+            }
         }
     }
 
@@ -138,6 +162,9 @@ impl ListFormat {
         match self {
             ListFormat::Xml(_) => None,
             ListFormat::Json(api) => api.parameter_name,
+            ListFormat::RestJson(api) | ListFormat::RestXml(api) => {
+                api.parameter.as_ref().map(Location::name)
+            }
         }
     }
 }
@@ -177,7 +204,8 @@ impl Method {
 
 #[derive(Serialize, Clone)]
 pub(crate) struct ListJson {
-    pub(crate) method: JsonListMethod,
+    /// x-amz-target header of the POST request.
+    pub(crate) target: &'static str,
     pub(crate) service_name: &'static str,
     pub(crate) json: serde_json::Value,
     pub(crate) limit: Option<Limit>,
@@ -185,12 +213,6 @@ pub(crate) struct ListJson {
     pub(crate) parameter_name: Option<&'static str>,
 }
 
-#[derive(Serialize, Clone)]
-pub(crate) enum JsonListMethod {
-    Post { target: &'static str },
-    Get { path: &'static str },
-}
-
 impl ListJson {
     pub(crate) fn json_map(&self) -> Result<Map<String, Value>> {
         if let Value::Object(map) = &self.json {
@@ -201,9 +223,45 @@ impl ListJson {
     }
 }
 
+/// Where a value is put in the request of rest-json and rest-xml apis.
+#[derive(Serialize, Clone, Debug)]
+pub(crate) enum Location {
+    /// Replaces `{name}` in the path.
+    Path(&'static str),
+    Query(&'static str),
+    Header(&'static str),
+    /// Member of the json body, only for rest-json.
+    Body(&'static str),
+}
+
+impl Location {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Location::Path(name)
+            | Location::Query(name)
+            | Location::Header(name)
+            | Location::Body(name) => name,
+        }
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub(crate) struct ListRest {
+    pub(crate) method: Method,
+    pub(crate) path: &'static str,
+    pub(crate) service_name: &'static str,
+    pub(crate) params: Vec<(Location, &'static str)>,
+    pub(crate) limit: Option<(Location, i64)>,
+    pub(crate) token: Option<Location>,
+    pub(crate) parameter: Option<Location>,
+    /// Tags of list items, only for rest-xml.
+    pub(crate) iteration_tag: Vec<&'static str>,
+    pub(crate) region: Option<rusoto_core::Region>,
+}
+
 #[derive(Serialize, Clone)]
 pub(crate) struct GetApi {
-    pub(crate) param_path: Vec<&'static str>,
+    pub(crate) param_path: Path,
     pub(crate) format: GetFormat,
     pub(crate) document: DocumentUrl,
 }
@@ -212,17 +270,24 @@ pub(crate) struct GetApi {
 pub(crate) enum GetFormat {
     Xml(GetXml),
     Json(GetJson),
+    RestJson(GetRest),
+    /// No resource has a rest-xml get api yet.
+    #[allow(dead_code)]
+    RestXml(GetRest),
 }
 
 impl GetFormat {
-    pub(crate) fn name(&self) -> &'static str {
+    pub(crate) fn name(&self) -> String {
         match self {
-            GetFormat::Xml(api) => api.action,
+            GetFormat::Xml(api) => api.action.to_string(),
             GetFormat::Json(GetJson {
                 target: Some(target),
                 ..
-            }) => target,
-            _ => "-",
+            }) => target.to_string(),
+            GetFormat::RestJson(api) | GetFormat::RestXml(api) => {
+                format!("{} {}", api.method.to_str(), api.path)
+            }
+            _ => "-".to_string(),
         }
     }
 }
@@ -244,6 +309,18 @@ pub(crate) struct GetJson {
     pub(crate) parameter_name: Option<&'static str>,
 }
 
+#[derive(Serialize, Clone)]
+pub(crate) struct GetRest {
+    pub(crate) method: Method,
+    pub(crate) path: &'static str,
+    pub(crate) service_name: &'static str,
+    pub(crate) params: Vec<(Location, &'static str)>,
+    pub(crate) parameter: Location,
+    /// Tags of list items, only for rest-xml.
+    pub(crate) iteration_tag: Vec<&'static str>,
+    pub(crate) region: Option<rusoto_core::Region>,
+}
+
 impl Clone for Box<dyn AwsResource> {
     fn clone(&self) -> Self {
         resource_by_name(&self.name())
@@ -280,12 +357,7 @@ pub(crate) trait AwsResource: Send + Sync {
 
     fn console_url(&self, list: &Yaml, get: &Option<Yaml>, region: &str) -> String {
         if let Some(resource_url) = &self.info().resource_url {
-            let mut line = match resource_url {
-                ResourceUrl::Regional(url) => {
-                    format!("https://{}.console.aws.amazon.com/{}", region, url)
-                }
-                ResourceUrl::Global(url) => format!("https://console.aws.amazon.com/{}", url),
-            };
+            let mut line = resource_url.url(region);
             for (key, param, encode) in self.url_params(list, get).unwrap_or(vec![]).iter() {
                 line = line.replace(
                     &("{".to_string() + key + "}"),
@@ -359,6 +431,14 @@ pub(crate) trait AwsResource: Send + Sync {
             ListFormat::Json(ListJson {
                 limit: Some(limit), ..
             }) => format!("{}: {}", limit.name, limit.max),
+            ListFormat::RestJson(ListRest {
+                limit: Some((location, max)),
+                ..
+            })
+            | ListFormat::RestXml(ListRest {
+                limit: Some((location, max)),
+                ..
+            }) => format!("{}: {}", location.name(), max),
             _ => "-".to_owned(),
         }
     }
@@ -366,6 +446,36 @@ pub(crate) trait AwsResource: Send + Sync {
     fn has_resource_url(&self) -> bool {
         self.info().resource_url.is_some()
     }
+
+    /// Global resources like iam or cloudfront are the same in every region.
+    fn is_global(&self) -> bool {
+        match (&self.info().resource_url, &self.info().list_api.format) {
+            (Some(ResourceUrl::Global(_)), _) => true,
+            (_, ListFormat::Xml(ListXml { region, .. })) => region.is_some(),
+            (_, ListFormat::RestJson(ListRest { region, .. })) => region.is_some(),
+            (_, ListFormat::RestXml(ListRest { region, .. })) => region.is_some(),
+            _ => false,
+        }
+    }
+
+    fn targets(&self, opts: &Opts) -> Vec<Target> {
+        let mut targets = vec![];
+        for profile in opts.profiles() {
+            let opts = opts.with_profile(&profile);
+            let regions = if self.is_global() {
+                opts.regions().into_iter().take(1).collect()
+            } else {
+                opts.regions()
+            };
+            for region in regions {
+                targets.push(Target {
+                    profile: profile.clone(),
+                    region,
+                });
+            }
+        }
+        targets
+    }
 }
 
 pub(crate) enum ExecuteTarget {
@@ -386,10 +496,14 @@ pub(crate) async fn execute_command(sub_command: &SubCommand, opts: Opts) -> Res
     use ExecuteTarget::*;
     for resource in all_resources() {
         match resource.take_command(sub_command, &opts.clone()) {
-            Ok(ExecuteThis { parameter }) => {
-                crate::ui::tui(opts.clone(), parameter, Some(resource)).await?;
-            }
+            Ok(ExecuteThis { parameter }) => match &opts.output {
+                Some(format) => crate::output::print(&*resource, &parameter, &opts, format).await?,
+                None => crate::ui::tui(opts.clone(), parameter, Some(resource)).await?,
+            },
             Ok(ParameterFromResource { .. }) | Ok(ParameterFromList { .. }) => {
+                if opts.output.is_some() {
+                    return Err(crate::output::requires_parameter(&*resource));
+                }
                 crate::ui::tui(opts.clone(), None, Some(resource)).await?;
             }
             Ok(ExecuteTarget::Null) => (),
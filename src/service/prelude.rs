@@ -5,10 +5,12 @@ pub(crate) use crate::opts::Opts;
 pub(crate) use crate::opts::*;
 pub(crate) use crate::service::{
     merge_yamls, next_token, resource_by_name, tag_value, AwsResource, DocumentUrl, ExecuteTarget,
-    GetApi, GetFormat, GetJson, GetXml, Info, JsonListMethod, Limit, ListApi, ListFormat, ListJson,
-    ListXml, Method, ParamSet, ResourceList, ResourceUrl::Global, ResourceUrl::Regional,
+    GetApi, GetFormat, GetJson, GetRest, GetXml, Info, Limit, ListApi, ListFormat, ListJson,
+    ListRest, ListXml, Location, Method, ParamSet, ResourceList, ResourceUrl::Global,
+    ResourceUrl::Regional,
 };
 pub(crate) use crate::show::{byte, duration, raw, span, time, Section};
+pub(crate) use crate::yaml_path::Path;
 pub(crate) use inflector::Inflector;
 pub(crate) use rusoto_core::Region;
 pub(crate) use rusoto_signature::SignedRequest;
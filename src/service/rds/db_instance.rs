@@ -62,11 +62,11 @@ impl AwsResource for Resource {
         Section::new(list)
             .yaml_name("db_instance_identifier")
             .resource_url(self.console_url(list, get, region))
-            .raw_n("status", &["db_instance_status"])
-            .raw_n("cluster", &["db_cluster_identifier"])
+            .raw_n("status", "db_instance_status")
+            .raw_n("cluster", "db_cluster_identifier")
             .raw("engine")
             .raw("engine_version")
-            .raw_n("class", &["db_instance_class"])
+            .raw_n("class", "db_instance_class")
             .raw("availability_zone")
             .raw("multi_az")
             .time("instance_create_time")
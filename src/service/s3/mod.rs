@@ -1 +1,2 @@
 pub(crate) mod bucket;
+pub(crate) mod object;
@@ -0,0 +1,86 @@
+use crate::service::prelude::*;
+
+#[derive(Serialize)]
+pub(crate) struct Resource {
+    info: Info,
+}
+
+pub(crate) fn new() -> Resource {
+    Resource {
+        info: Info {
+            sub_command: None,
+            key_attribute: Some("key"),
+            service_name: "s3",
+            resource_type_name: "object",
+            header: vec!["key", "size", "last modified"],
+            list_api: ListApi {
+                format: ListFormat::RestXml(ListRest {
+                    method: Method::Get,
+                    path: "/{Bucket}",
+                    service_name: "s3",
+                    params: vec![(Location::Query("list-type"), "2")],
+                    limit: Some((Location::Query("max-keys"), 1000)),
+                    token: Some(Location::Query("continuation-token")),
+                    parameter: Some(Location::Path("Bucket")),
+                    iteration_tag: vec!["Contents"],
+                    region: None,
+                }),
+                document: DocumentUrl("AmazonS3/latest/API/API_ListObjectsV2.html"),
+            },
+            get_api: None,
+            resource_url: None,
+        },
+    }
+}
+
+impl AwsResource for Resource {
+    fn info(&self) -> &Info {
+        &self.info
+    }
+
+    fn take_command(&self, sub_command: &SubCommand, opts: &Opts) -> Result<ExecuteTarget> {
+        if let SubCommand::S3 {
+            command: S3Command::Object { bucket },
+        } = sub_command
+        {
+            match bucket {
+                Some(name) => Ok(ExecuteTarget::ExecuteThis {
+                    parameter: Some(name.clone()),
+                }),
+                None => Ok(self.without_param(opts)),
+            }
+        } else {
+            Ok(ExecuteTarget::Null)
+        }
+    }
+
+    fn without_param(&self, _opts: &Opts) -> ExecuteTarget {
+        ExecuteTarget::ParameterFromResource {
+            param_resource: resource_by_name("s3_bucket"),
+        }
+    }
+
+    fn list_and_next_token(&self, yaml: &Yaml) -> (ResourceList, Option<String>) {
+        (
+            make_resource_list(self, &yaml["contents"]),
+            next_token(yaml, Some("next_continuation_token")),
+        )
+    }
+
+    fn line(&self, list: &Yaml, _get: &Option<Yaml>) -> Vec<String> {
+        vec![
+            raw(&list["key"]),
+            byte(&list["size"]),
+            time(&list["last_modified"]),
+        ]
+    }
+
+    fn detail(&self, list: &Yaml, _get: &Option<Yaml>, _region: &str) -> Section {
+        Section::new(list)
+            .yaml_name("key")
+            .byte("size")
+            .time("last_modified")
+            .raw("storage_class")
+            .raw("e_tag")
+    }
+}
@@ -17,9 +17,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["status", "name", "time", "end at"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "AmazonSSM.DescribeAutomationExecutions",
-                    },
+                    target: "AmazonSSM.DescribeAutomationExecutions",
                     service_name: "ssm",
                     json: json!({}),
                     limit: Some(Limit {
@@ -66,7 +64,7 @@ impl AwsResource for Resource {
         Section::new(list)
             .yaml_name("automation_execution_id")
             .resource_url(self.console_url(list, get, region))
-            .raw_n("status", &["automation_execution_status"])
+            .raw_n("status", "automation_execution_status")
             .duration(
                 "execution time",
                 ("execution_start_time", "execution_end_time"),
@@ -17,9 +17,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["type", "name", "owner"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "AmazonSSM.ListDocuments",
-                    },
+                    target: "AmazonSSM.ListDocuments",
                     service_name: "ssm",
                     json: json!({}),
                     limit: Some(Limit {
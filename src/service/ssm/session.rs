@@ -15,9 +15,7 @@ pub(crate) fn new() -> Resource {
             header: vec!["id", "target", "date"],
             list_api: ListApi {
                 format: ListFormat::Json(ListJson {
-                    method: JsonListMethod::Post {
-                        target: "AmazonSSM.DescribeSessions",
-                    },
+                    target: "AmazonSSM.DescribeSessions",
                     service_name: "ssm",
                     json: json!({}),
                     limit: Some(Limit {
@@ -100,8 +98,8 @@ impl AwsResource for Resource {
             .section(
                 Section::new(&yaml["output_url"])
                     .string_name("output url")
-                    .raw_n("cloudwatch", &["cloud_watch_output_url"])
-                    .raw_n("s3", &["s3_output_url"]),
+                    .raw_n("cloudwatch", "cloud_watch_output_url")
+                    .raw_n("s3", "s3_output_url"),
             )
     }
 }
@@ -1,5 +1,5 @@
 use crate::color;
-use crate::yaml_path::apply_path;
+use crate::yaml_path::Path;
 use std::cmp::max;
 use tui::style::Color;
 use tui::style::Style;
@@ -107,13 +107,12 @@ impl Section {
         self
     }
 
-    pub(crate) fn yaml_name(mut self, key: &str) -> Self {
-        self.name = Some(Name::Yaml(self.yaml[key].clone()));
-        self
+    pub(crate) fn yaml_name(self, key: &str) -> Self {
+        self.yaml_name_n(key)
     }
 
-    pub(crate) fn yaml_name_n(mut self, path: &[&str]) -> Self {
-        self.name = Some(Name::Yaml(apply_path(&self.yaml, path).clone()));
+    pub(crate) fn yaml_name_n(mut self, path: &str) -> Self {
+        self.name = Some(Name::Yaml(self.apply(path)));
         self
     }
 
@@ -137,12 +136,11 @@ impl Section {
     }
 
     pub(crate) fn raw(self, key: &str) -> Self {
-        let raw = self::raw(&self.yaml[key]);
-        self.child(&key.replace("_", " "), &raw)
+        self.raw_n(&key.replace("_", " "), key)
     }
 
-    pub(crate) fn raw_n(self, name: &str, path: &[&str]) -> Self {
-        let raw = self::raw(apply_path(&self.yaml, path));
+    pub(crate) fn raw_n(self, name: &str, path: &str) -> Self {
+        let raw = self::raw(&self.apply(path));
         self.child(name, &raw)
     }
 
@@ -151,30 +149,33 @@ impl Section {
     }
 
     pub(crate) fn time(self, key: &str) -> Self {
-        let time = self::time(&self.yaml[key]);
-        self.child(&key.replace("_", " "), &time)
+        self.time_n(&key.replace("_", " "), key)
     }
 
-    pub(crate) fn time_n(self, name: &str, path: &[&str]) -> Self {
-        let time = self::time(apply_path(&self.yaml, path));
+    pub(crate) fn time_n(self, name: &str, path: &str) -> Self {
+        let time = self::time(&self.apply(path));
         self.child(name, &time)
     }
 
     pub(crate) fn byte(self, key: &str) -> Self {
-        let byte = self::byte(&self.yaml[key]);
-        self.child(&key.replace("_", " "), &byte)
+        self.byte_n(&key.replace("_", " "), key)
     }
 
-    pub(crate) fn byte_n(self, name: &str, path: &[&str]) -> Self {
-        let byte = self::byte(apply_path(&self.yaml, path));
+    pub(crate) fn byte_n(self, name: &str, path: &str) -> Self {
+        let byte = self::byte(&self.apply(path));
         self.child(name, &byte)
     }
 
-    pub(crate) fn milli_sec_n(self, name: &str, path: &[&str]) -> Self {
-        let sec = self::milli_sec(apply_path(&self.yaml, path));
+    pub(crate) fn milli_sec_n(self, name: &str, path: &str) -> Self {
+        let sec = self::milli_sec(&self.apply(path));
         self.child(name, &sec)
     }
 
+    /// Value at the path of the definition, like `placement.availability_zone`.
+    fn apply(&self, path: &str) -> Yaml {
+        Path::new(path).apply(&self.yaml)
+    }
+
     fn child(mut self, name: &str, val: &str) -> Self {
         self.children.push(Child::Attribute(Attribute {
             name: Name::String(name.to_string()),
@@ -470,6 +471,7 @@ pub(crate) fn raw(yaml: &Yaml) -> String {
         Yaml::String(string) => string.clone(),
         Yaml::Integer(int) => format!("{}", int),
         Yaml::Boolean(bool) => format!("{:?}", bool),
+        Yaml::Array(items) => items.iter().map(raw).collect::<Vec<_>>().join(", "),
         _ => "None".to_string(),
     }
 }
@@ -0,0 +1,205 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use crate::service::tag_value;
+use crate::yaml_path::{self, Path};
+use yaml_rust::Yaml;
+
+/// Keys of tag lists like `[{key: Name, value: web}]` in list and get yamls.
+const TAG_KEYS: [&str; 3] = ["tag_set", "tags", "tag_list"];
+
+/// Output template like "{instance_id} {tag:Name} {placement.availability_zone}".
+#[derive(Debug, Clone)]
+pub(crate) struct Template(Vec<Part>);
+
+#[derive(Debug, Clone)]
+enum Part {
+    Text(String),
+    Field(String, Path),
+    Tag(String),
+}
+
+impl Template {
+    /// "{{" and "}}" are braces, "\n" and "\t" are a new line and a tab.
+    pub(crate) fn parse(template: &str) -> Result<Template> {
+        let invalid =
+            |reason: &str| ArgumentError(format!("invalid format \"{}\": {}", template, reason));
+        let mut parts = vec![];
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    text.push(c);
+                }
+                ('\\', Some('n')) | ('\\', Some('t')) | ('\\', Some('\\')) => {
+                    text.push(match chars.next() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        _ => '\\',
+                    });
+                }
+                ('{', _) => {
+                    let mut field = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => field.push(c),
+                            None => return Err(invalid("no closing }")),
+                        }
+                    }
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    let field = field.trim();
+                    parts.push(match field.strip_prefix("tag:") {
+                        _ if field.is_empty() => return Err(invalid("empty field {}")),
+                        Some(name) => Part::Tag(name.to_string()),
+                        None => Part::Field(field.to_string(), Path::parse(field)?),
+                    });
+                }
+                ('}', _) => return Err(invalid("no opening {")),
+                (c, _) => text.push(c),
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(Template(parts))
+    }
+
+    /// A text per resource of the list and get yamls, fields which no resource has are errors.
+    pub(crate) fn render(&self, items: &[(&Yaml, &Option<Yaml>)]) -> Result<Vec<String>> {
+        for part in &self.0 {
+            let (name, found) = match part {
+                Part::Text(_) => continue,
+                Part::Field(name, path) => (
+                    name.clone(),
+                    items.iter().any(|(list, get)| {
+                        !yaml_path::apply_list_or_get(path, list, get).is_badvalue()
+                    }),
+                ),
+                Part::Tag(name) => (
+                    format!("tag:{}", name),
+                    items
+                        .iter()
+                        .any(|(list, get)| !tag(name, list, get).is_badvalue()),
+                ),
+            };
+            if !found {
+                return Err(ArgumentError(format!(
+                    "unknown field {{{}}} in format",
+                    name
+                )));
+            }
+        }
+
+        Ok(items
+            .iter()
+            .map(|(list, get)| {
+                self.0
+                    .iter()
+                    .map(|part| match part {
+                        Part::Text(text) => text.clone(),
+                        Part::Field(_, path) => {
+                            yaml_path::text(&yaml_path::apply_list_or_get(path, list, get))
+                        }
+                        Part::Tag(name) => yaml_path::text(&tag(name, list, get)),
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+fn tag(name: &str, list: &Yaml, get: &Option<Yaml>) -> Yaml {
+    std::iter::once(list)
+        .chain(get.iter())
+        .flat_map(|yaml| TAG_KEYS.iter().map(move |key| tag_value(&yaml[*key], name)))
+        .find(|value| !value.is_badvalue())
+        .cloned()
+        .unwrap_or(Yaml::BadValue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yaml_rust::YamlLoader;
+
+    fn load(yaml: &str) -> Yaml {
+        YamlLoader::load_from_str(yaml).unwrap().remove(0)
+    }
+
+    fn render(template: &str, list: &Yaml, get: &Option<Yaml>) -> Result<Vec<String>> {
+        Template::parse(template)?.render(&[(list, get)])
+    }
+
+    fn instance() -> Yaml {
+        load(
+            r#"
+instance_id: i-1
+placement:
+  availability_zone: us-east-1a
+tags:
+  - key: Name
+    value: web
+"#,
+        )
+    }
+
+    #[test]
+    fn fields_and_tags() {
+        assert_eq!(
+            render(
+                "{instance_id} {tag:Name} {placement.availability_zone}",
+                &instance(),
+                &None
+            )
+            .unwrap(),
+            vec!["i-1 web us-east-1a"]
+        );
+        assert_eq!(
+            render("{ instance_id }\\t{ tag:Name }", &instance(), &None).unwrap(),
+            vec!["i-1\tweb"]
+        );
+    }
+
+    #[test]
+    fn fields_of_get() {
+        let list = load("name: bucket");
+        let get = Some(load(
+            "tag_set: [{key: env, value: prod}]\nlocation: {region: ap}",
+        ));
+        assert_eq!(
+            render("{name}:{tag:env}:{location.region}", &list, &get).unwrap(),
+            vec!["bucket:prod:ap"]
+        );
+    }
+
+    #[test]
+    fn braces_and_escapes() {
+        assert_eq!(
+            render("{{{instance_id}}}\\n\\\\", &instance(), &None).unwrap(),
+            vec!["{i-1}\n\\"]
+        );
+        assert_eq!(
+            render("{{not a field}}", &instance(), &None).unwrap(),
+            vec!["{not a field}"]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        for template in &["{instance_id", "instance_id}", "{}", "{ }", "{a[0}"] {
+            assert!(Template::parse(template).is_err(), "{}", template);
+        }
+    }
+
+    #[test]
+    fn unknown_fields() {
+        assert!(render("{nothing}", &instance(), &None).is_err());
+        assert!(render("{tag:nothing}", &instance(), &None).is_err());
+    }
+}
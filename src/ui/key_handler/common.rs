@@ -1,3 +1,4 @@
+use crate::error::Error;
 use crate::help::Help;
 use crate::ui::scene::SceneBase;
 use crate::ui::NextScene;
@@ -9,10 +10,10 @@ pub(crate) fn common(key: Key, base: &mut SceneBase, exit_to_menu: bool) -> Opti
             if exit_to_menu {
                 Some(base.back_or_root_menu())
             } else {
-                Some(NextScene::Exit(None))
+                Some(NextScene::Exit(Err(Error::Cancelled)))
             }
         }
-        Key::Ctrl('c') | Key::Char('C') => Some(NextScene::Exit(None)),
+        Key::Ctrl('c') | Key::Char('C') => Some(NextScene::Exit(Err(Error::Cancelled))),
         _ => None,
     }
 }
@@ -10,3 +10,5 @@ pub(in crate::ui) mod section_popup_open;
 pub(in crate::ui) use section_popup_open::section_popup_open;
 pub(in crate::ui) mod popup;
 pub(in crate::ui) use popup::popup;
+pub(in crate::ui) mod target_popup_open;
+pub(in crate::ui) use target_popup_open::target_popup_open;
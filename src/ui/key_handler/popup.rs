@@ -1,3 +1,4 @@
+use crate::error::Error;
 use crate::help::Help;
 use crate::ui::scene::SceneBase;
 use crate::ui::NextScene;
@@ -13,7 +14,7 @@ pub(in crate::ui) fn popup(
         match key {
             Key::Esc | Key::Ctrl('v') | Key::Char('V') => return base.back_or_root_menu(),
 
-            Key::Ctrl('c') | Key::Char('C') => return NextScene::Exit(None),
+            Key::Ctrl('c') | Key::Char('C') => return NextScene::Exit(Err(Error::Cancelled)),
 
             Key::Down => *offset = std::cmp::min(line_len, *offset + 1),
 
@@ -0,0 +1,49 @@
+use crate::help::Help;
+use crate::ui::scene;
+use crate::ui::scene::list_option::Choice;
+use rustbox::keyboard::Key;
+
+pub(crate) fn target_popup_open(
+    key: Key,
+    resource_scene: &scene::resources::Scene,
+) -> Option<scene::list_option::Scene> {
+    let opts = resource_scene.base.opts();
+    let (choice, name, items, current) = match key {
+        Key::Char('Z') | Key::Ctrl('z') => (
+            Choice::Region(Box::new(resource_scene.clone())),
+            "region",
+            crate::opts::region_names(),
+            opts.region_name(),
+        ),
+        Key::Char('P') | Key::Ctrl('p') => (
+            Choice::Profile(Box::new(resource_scene.clone())),
+            "profile",
+            crate::api::profile::names(),
+            opts.profile_name(),
+        ),
+        _ => return None,
+    };
+
+    let mut popup = scene::list_option::new(
+        resource_scene.base.duplicate(
+            None,
+            Some(Box::new(crate::ui::UiScene::Resource(
+                resource_scene.clone(),
+            ))),
+        ),
+        choice,
+        name,
+        &items,
+    );
+    if let Some(index) = items.iter().position(|item| *item == current) {
+        popup.option_list.state.select(Some(index));
+    }
+    Some(popup)
+}
+
+pub(crate) fn helps() -> Vec<Help> {
+    vec![
+        Help::new("Z", None, "popup region list to switch the region"),
+        Help::new("P", None, "popup profile list to switch the profile"),
+    ]
+}
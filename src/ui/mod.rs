@@ -5,24 +5,27 @@ mod scene;
 mod util;
 pub mod widget;
 
+use crate::api::identity::Identity;
 use crate::opts::Opts;
 use crate::service::AwsResource;
 use crate::ui::scene::SceneBase;
 use crate::ui::util::event::{Event, Events};
 use rustbox::keyboard::Key;
+use std::collections::HashMap;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tui::Frame;
 use tui::Terminal;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 pub(crate) type TypedTerminal = Terminal<RustboxBackend>;
 
 pub(crate) enum NextScene {
     Scene(UiScene),
     Same,
-    Exit(Option<String>),
+    /// The text to print, or why nothing is printed.
+    Exit(Result<String>),
 }
 
 #[derive(Clone)]
@@ -136,7 +139,7 @@ pub(in crate::ui) fn select_next_scene(
             } => {
                 let option = scene::list_option::new(
                     SceneBase::with_history(opts.clone(), tx, current_scene.clone()),
-                    resource.clone(),
+                    scene::list_option::Choice::Parameter(resource.clone()),
                     &option_name,
                     &option_list,
                 );
@@ -173,14 +176,81 @@ struct UiState {
     viewer_mode: ViewerMode,
     logs: crate::log::Logs,
     pub(in crate::ui) api_count: usize,
+    pub(in crate::ui) scheduler: util::scheduler::Scheduler,
+    /// Identities by profile, None while requesting.
+    identities: HashMap<String, Option<Identity>>,
+    identity_tx: mpsc::Sender<(String, Result<Identity>)>,
+    identity_rx: mpsc::Receiver<(String, Result<Identity>)>,
 }
 
 impl UiState {
-    pub fn new() -> Self {
+    pub fn new(opts: &Opts) -> Self {
+        let (identity_tx, identity_rx) = mpsc::channel(100);
         UiState {
             viewer_mode: ViewerMode::Summary,
             logs: crate::log::Logs::new(),
             api_count: 0,
+            scheduler: util::scheduler::new(opts.concurrency()),
+            identities: HashMap::new(),
+            identity_tx,
+            identity_rx,
+        }
+    }
+
+    /// Request identities of the profiles not requested yet, none while offline or replaying.
+    pub(in crate::ui) fn identify(&mut self, opts: &Opts) {
+        if opts.offline || opts.replay.is_some() {
+            return;
+        }
+        for profile in opts.profiles() {
+            if self.identities.contains_key(&profile) {
+                continue;
+            }
+            self.identities.insert(profile.clone(), None);
+
+            let opts = opts.with_profile(&profile);
+            let mut tx = self.identity_tx.clone();
+            tokio::spawn(async move {
+                let identity = crate::api::identity::call(&opts).await;
+                let _ = tx.send((profile, identity)).await;
+            });
+            self.api_count_up();
+        }
+    }
+
+    /// Store received identities, true when any is received.
+    fn receive_identities(&mut self) -> bool {
+        let mut received = false;
+        while let Ok((profile, identity)) = self.identity_rx.try_recv() {
+            match identity {
+                Ok(identity) => {
+                    self.identities.insert(profile, Some(identity));
+                }
+                Err(e) => self
+                    .logs
+                    .error(&format!("failed to get the account of {}: {}", profile, e)),
+            }
+            received = true;
+        }
+        received
+    }
+
+    pub(in crate::ui) fn identity(&self, profile: &str) -> Option<&Identity> {
+        self.identities.get(profile).and_then(Option::as_ref)
+    }
+
+    /// Account of the single profile for the status line, nothing while unknown.
+    pub(in crate::ui) fn identity_status(&self, opts: &Opts) -> crate::show::Texts {
+        use crate::show::{Texts, Txt};
+        match opts.profiles().as_slice() {
+            [profile] => match self.identity(profile) {
+                Some(identity) => Texts(vec![Txt::colored(
+                    &format!(" [{}]", identity.account_label()),
+                    crate::color::INACTIVE,
+                )]),
+                None => Texts::new(),
+            },
+            _ => Texts::new(),
         }
     }
 
@@ -194,6 +264,15 @@ impl UiState {
     pub fn api_count_up(&mut self) {
         self.api_count += 1;
     }
+
+    /// Log the retries of the requests, true when any is logged.
+    fn receive_retries(&mut self, opts: &Opts) -> bool {
+        let retries = opts.retries.take();
+        for retry in &retries {
+            self.logs.info(retry);
+        }
+        !retries.is_empty()
+    }
 }
 
 pub(crate) async fn tui(
@@ -202,8 +281,13 @@ pub(crate) async fn tui(
     resource: Option<Box<dyn AwsResource>>,
 ) -> Result<()> {
     let mut terminal = util::terminal()?;
+    if !assume_roles(&mut terminal, &opts).await? {
+        return Err(Error::Cancelled);
+    }
+
     let mut events = util::event::new();
-    let mut ui_state = UiState::new();
+    let mut ui_state = UiState::new(&opts);
+    ui_state.identify(&opts);
 
     let mut scene = match resource {
         Some(resource) => select_next_scene(
@@ -219,6 +303,9 @@ pub(crate) async fn tui(
             events.tx.clone(),
         ))),
     };
+    if let UiScene::Resource(scene) = &mut scene {
+        scene.start_search();
+    }
 
     let output_text;
     let mut keys = vec![];
@@ -233,6 +320,10 @@ pub(crate) async fn tui(
             }
         }
 
+        if ui_state.receive_identities() | ui_state.receive_retries(&opts) {
+            scene.set_should_draw();
+        }
+
         if scene.take_should_draw() {
             terminal.draw(|mut f| scene.draw(&mut ui_state, &mut f))?;
         }
@@ -242,13 +333,32 @@ pub(crate) async fn tui(
 
     drop(terminal);
 
-    if let Some(text) = output_text {
-        print!("{}", text);
-    }
-
+    print!("{}", output_text?);
     Ok(())
 }
 
+/// Prompt mfa token codes of the roles before the first scene, false when the user quits.
+async fn assume_roles(terminal: &mut TypedTerminal, opts: &Opts) -> Result<bool> {
+    for profile in opts.profiles() {
+        let opts = opts.with_profile(&profile);
+        if let Some(serial) = crate::api::credentials::mfa_serial_to_prompt(&opts) {
+            let mut message = format!("profile: {}", profile);
+            loop {
+                match util::prompt::mfa_token(terminal, &serial, &message)? {
+                    Some(token) => {
+                        match crate::api::credentials::assume_role_with_token(&opts, &token).await {
+                            Ok(()) => break,
+                            Err(e) => message = e.to_string(),
+                        }
+                    }
+                    None => return Ok(false),
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
 fn peek_event(terminal: &mut Terminal<RustboxBackend>, scene: &mut UiScene) -> Vec<Key> {
     let mut keys = vec![];
     for _ in 0..10 {
@@ -171,7 +171,9 @@ impl Scene {
     ) {
         let (status, (search, table, log), (info, viewer, help)) = layout::main::layout(f.size());
 
-        self.status.draw(&mut f, status, self.status(true));
+        let mut status_texts = self.status(true);
+        status_texts.append(&mut ui_state.identity_status(&self.base.opts));
+        self.status.draw(&mut f, status, status_texts);
         self.search.draw(&mut f, search, &self.search_text);
         self.table.draw(&mut f, table);
         self.log.draw(&mut f, log, ui_state.logs.to_text(2));
@@ -179,7 +181,7 @@ impl Scene {
         self.info.draw(
             &mut f,
             info,
-            &self.base.opts.region_name(),
+            &self.base.opts,
             self.viewer.scroll,
             self.viewer.line_len,
             false,
@@ -1,8 +1,7 @@
 use super::SceneBase;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::service::AwsResource;
-use crate::ui::UiState;
-use crate::ui::{layout, select_next_scene, widget, NextScene};
+use crate::ui::{layout, scene, select_next_scene, widget, NextScene, UiScene, UiState};
 use rustbox::keyboard::Key;
 use tui::backend::RustboxBackend;
 use tui::terminal::Frame;
@@ -11,19 +10,27 @@ use widget::util::list;
 #[derive(Clone)]
 pub(crate) struct Scene {
     pub(crate) base: super::SceneBase,
-    resource: Box<dyn AwsResource>,
+    choice: Choice,
     pub(crate) option_list: widget::TextList,
 }
 
+/// What the selected option is used for.
+#[derive(Clone)]
+pub(crate) enum Choice {
+    Parameter(Box<dyn AwsResource>),
+    Region(Box<scene::resources::Scene>),
+    Profile(Box<scene::resources::Scene>),
+}
+
 pub(crate) fn new(
     base: SceneBase,
-    resource: Box<dyn AwsResource>,
+    choice: Choice,
     option_name: &str,
     option_list: &Vec<String>,
 ) -> Scene {
     Scene {
         base,
-        resource,
+        choice,
         option_list: widget::text_list::new(option_name, option_list),
     }
 }
@@ -38,18 +45,11 @@ impl Scene {
             match key {
                 Key::Esc => return Ok(self.base.back_or_root_menu()),
 
-                Key::Enter => {
-                    return Ok(NextScene::Scene(select_next_scene(
-                        self.base.history.clone(),
-                        &self.base.opts,
-                        &self.option_list.selected_item(),
-                        self.resource.clone(),
-                        ui_state,
-                        self.base.tx.clone(),
-                    )))
-                }
+                Key::Enter => return Ok(self.select(ui_state)),
 
-                Key::Ctrl('c') | Key::Char('C') => return Ok(NextScene::Exit(None)),
+                Key::Ctrl('c') | Key::Char('C') => {
+                    return Ok(NextScene::Exit(Err(Error::Cancelled)))
+                }
 
                 Key::Down | Key::Ctrl('j') | Key::Char('J') => {
                     list::next(&mut self.option_list.state, self.option_list.items.len());
@@ -68,6 +68,29 @@ impl Scene {
         Ok(NextScene::Same)
     }
 
+    fn select(&self, ui_state: &mut UiState) -> NextScene {
+        let selected = self.option_list.selected_item();
+        NextScene::Scene(match (&self.choice, selected) {
+            (Choice::Parameter(resource), selected) => select_next_scene(
+                self.base.history.clone(),
+                &self.base.opts,
+                &selected,
+                resource.clone(),
+                ui_state,
+                self.base.tx.clone(),
+            ),
+            (Choice::Region(scene), Some(region)) => {
+                UiScene::Resource(scene.retarget(scene.base.opts.with_region(&region), ui_state))
+            }
+            (Choice::Profile(scene), Some(profile)) => {
+                UiScene::Resource(scene.retarget(scene.base.opts.with_profile(&profile), ui_state))
+            }
+            (Choice::Region(scene), None) | (Choice::Profile(scene), None) => {
+                UiScene::Resource(*scene.clone())
+            }
+        })
+    }
+
     pub(crate) fn draw(&mut self, mut f: &mut Frame<RustboxBackend>) {
         let popup = layout::popup::layout(50, 50, f.size());
         self.option_list.draw(&mut f, popup);
@@ -56,6 +56,10 @@ impl SceneBase {
         }
     }
 
+    pub(crate) fn opts(&self) -> &Opts {
+        &self.opts
+    }
+
     pub(crate) fn back_or_root_menu(&mut self) -> NextScene {
         NextScene::Scene(match &self.history {
             Some(last) => {
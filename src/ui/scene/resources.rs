@@ -1,9 +1,12 @@
 use super::SceneBase;
-use crate::api::file::store_yaml;
+use crate::api::file::store_yaml_with_header;
+use crate::api::identity::Identity;
+use crate::api::CallStats;
 use crate::color;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::help::{Help, Helps};
 use crate::log::Log;
+use crate::opts::{Opts, Target, TargetColumns};
 use crate::service::prelude::Yaml;
 use crate::service::AwsResource;
 use crate::show::Section;
@@ -15,6 +18,7 @@ use crate::ui::{
     widget, NextScene, UiScene, UiState,
 };
 use chrono::prelude::*;
+use futures::future::{AbortHandle, Abortable};
 use rustbox::keyboard::Key;
 use tui::backend::RustboxBackend;
 use tui::terminal::Frame;
@@ -25,10 +29,16 @@ pub(crate) struct Scene {
     pub(crate) base: super::SceneBase,
     pub(crate) search_text: String,
     parameter: Option<String>,
-    list_api_call: ListApiCall,
+    list_api_calls: Vec<TargetListApiCall>,
+    target_columns: TargetColumns,
     get_api_call: Vec<DateTime<Local>>,
     getting_item_index: Vec<usize>,
-    initial_request_count: usize,
+    cancellation: util::scheduler::Cancellation,
+    use_cache: bool,
+    cached_at: Option<DateTime<Local>>,
+    target_label: Option<String>,
+    /// Select the only resource or exit with nothing once all pages of every target are fetched.
+    auto_select: bool,
 
     resource: Box<dyn AwsResource>,
     pub(crate) next_resource: Option<Box<dyn AwsResource>>,
@@ -49,8 +59,25 @@ pub(crate) struct Scene {
 pub(crate) enum ListApiCall {
     None,
     Completed,
-    StillHave { next_token: String },
-    Requesting { start: DateTime<Local> },
+    StillHave {
+        next_token: String,
+    },
+    Requesting {
+        start: DateTime<Local>,
+        next_token: Option<String>,
+    },
+    TimedOut {
+        next_token: Option<String>,
+    },
+}
+
+/// List api state of a profile and region, resources of each target are fetched separately.
+#[derive(Clone)]
+struct TargetListApiCall {
+    target: Target,
+    api_call: ListApiCall,
+    initial_request_count: usize,
+    abort_handle: Option<AbortHandle>,
 }
 
 pub(in crate::ui) fn new(
@@ -61,6 +88,21 @@ pub(in crate::ui) fn new(
     ui_state: &mut UiState,
 ) -> Scene {
     let initial_request_count = base.opts.list_request_count();
+    let list_api_calls = resource
+        .targets(&base.opts)
+        .into_iter()
+        .map(|target| TargetListApiCall {
+            target,
+            api_call: ListApiCall::None,
+            initial_request_count,
+            abort_handle: None,
+        })
+        .collect::<Vec<_>>();
+    let targets = list_api_calls
+        .iter()
+        .map(|call| call.target.clone())
+        .collect::<Vec<_>>();
+    let target_columns = TargetColumns::new(&targets);
     let helps = Helps::new(all_helps(&*resource));
     let help_summary = helps.to_summary_text();
     let mut scene = Scene {
@@ -69,17 +111,22 @@ pub(in crate::ui) fn new(
         search_text: "".to_string(),
         parameter,
 
-        list_api_call: ListApiCall::None,
+        list_api_calls,
+        target_columns: target_columns.clone(),
         get_api_call: vec![],
         getting_item_index: vec![],
 
-        initial_request_count,
+        cancellation: util::scheduler::Cancellation::new(),
+        use_cache: true,
+        cached_at: None,
+        target_label: None,
+        auto_select: false,
         resource: resource.clone(),
         next_resource,
 
         status: widget::status::new(),
         search: widget::search::new(),
-        table: widget::resources::new(resource),
+        table: widget::resources::new(resource, target_columns),
         log: widget::log::new(),
         info: widget::info::new(),
         viewer: widget::viewer::new(Section::new_without_yaml()),
@@ -95,6 +142,7 @@ pub(in crate::ui) fn new(
 fn all_helps(resource: &dyn AwsResource) -> Vec<Help> {
     let mut all_helps = vec![];
     all_helps.append(&mut helps(resource));
+    all_helps.append(&mut crate::ui::key_handler::target_popup_open::helps());
     super::common_helps(&mut all_helps);
     all_helps
 }
@@ -127,6 +175,7 @@ fn helps(resource: &dyn AwsResource) -> Vec<Help> {
             "fetch resources if there still have been resource to fetch",
         ),
         Help::new("R", Some("reload"), "reload resources"),
+        Help::new("X", Some("cancel"), "cancel api requests in flight"),
         Help::new(
             "E",
             Some("export"),
@@ -156,11 +205,25 @@ impl Scene {
             Txt::raw(&self.resource.resource_full_name())
         });
 
+        if let Some(target_label) = &self.target_label {
+            texts.push(Txt::colored(
+                &format!(" @{}", target_label),
+                color::INACTIVE,
+            ));
+        }
+
         if current {
             if let Some(next_resource) = &self.next_resource {
                 texts.push(Txt::raw(" > "));
                 texts.push(Txt::raw(&next_resource.resource_full_name()))
             }
+
+            if let Some(cached_at) = &self.cached_at {
+                texts.push(Txt::colored(
+                    &format!(" (cached {} ago)", crate::api::cache::age(cached_at)),
+                    color::INACTIVE,
+                ));
+            }
         }
         Texts(texts)
     }
@@ -185,18 +248,29 @@ impl Scene {
             match util::event::next(events) {
                 Some(Event::ListResponse {
                     start,
+                    target,
                     yaml,
                     next_token,
+                    stats,
+                }) => {
+                    self.handle_list_response(ui_state, start, target, yaml, next_token, stats);
+                    self.base.should_draw = true;
+                }
+                Some(Event::ListFailure {
+                    start,
+                    target,
+                    timed_out,
                 }) => {
-                    self.handle_list_response(ui_state, start, yaml, next_token);
+                    self.handle_list_failure(start, target, timed_out);
                     self.base.should_draw = true;
                 }
                 Some(Event::GetResponse {
                     start,
                     yaml,
                     resource_index,
+                    stats,
                 }) => {
-                    self.handle_get_response(ui_state, start, yaml, resource_index);
+                    self.handle_get_response(ui_state, start, yaml, resource_index, stats);
                     self.base.should_draw = true;
                 }
                 Some(Event::Log(log)) => {
@@ -207,6 +281,10 @@ impl Scene {
             }
         }
 
+        if let Some(next_scene) = self.auto_select(ui_state) {
+            return Ok(next_scene);
+        }
+
         if table::select_any(self.table.filtered_len(), &mut self.table.state) {
             let section = self.create_section_and_get_detail(ui_state);
             self.viewer = widget::viewer::new(section);
@@ -215,14 +293,58 @@ impl Scene {
         Ok(NextScene::Same)
     }
 
+    /// Seed the search text with --search, the first scene only.
+    pub(in crate::ui) fn start_search(&mut self) {
+        let opts = &self.base.opts;
+        self.search_text = opts.search.clone().unwrap_or_default();
+        self.auto_select = opts.select_one || opts.exit_zero;
+        self.table.filter(&self.search_text);
+    }
+
+    /// --select-one and --exit-zero, when all list requests succeed and no key is pressed.
+    fn auto_select(&mut self, ui_state: &mut UiState) -> Option<NextScene> {
+        if !self.auto_select {
+            return None;
+        }
+        // next pages only add resources, so nothing is selected once too many match.
+        let filtered_len = self.table.filtered_len();
+        if 1 < filtered_len || (0 < filtered_len && !self.base.opts.select_one) {
+            self.auto_select = false;
+            return None;
+        }
+        if self
+            .list_api_calls
+            .iter()
+            .any(|call| matches!(call.api_call, ListApiCall::Requesting { .. }))
+        {
+            return None;
+        }
+        self.auto_select = false;
+
+        if !self
+            .list_api_calls
+            .iter()
+            .all(|call| matches!(call.api_call, ListApiCall::Completed))
+        {
+            return None;
+        }
+
+        match filtered_len {
+            1 if self.base.opts.select_one => {
+                self.table.state.select(Some(0));
+                Some(self.select_resource(ui_state))
+            }
+            0 if self.base.opts.exit_zero => Some(NextScene::Exit(Err(Error::NoSelection))),
+            _ => None,
+        }
+    }
+
     fn create_section_and_get_detail(&mut self, ui_state: &mut UiState) -> Section {
         match self.table.selected_item() {
             Some(item) => {
-                let section = self.resource.detail(
-                    &item.list_yaml,
-                    &item.get_yaml,
-                    &self.base.opts.region_name(),
-                );
+                let section =
+                    self.resource
+                        .detail(&item.list_yaml, &item.get_yaml, &item.target.region);
                 self.get_detail(&item, ui_state);
                 section
             }
@@ -245,6 +367,7 @@ impl Scene {
         start: DateTime<Local>,
         yaml: Yaml,
         resource_index: usize,
+        stats: CallStats,
     ) {
         if !self.get_api_call.contains(&start) {
             return;
@@ -252,10 +375,17 @@ impl Scene {
         self.getting_item_index.retain(|i| *i != resource_index);
 
         let duration = Local::now().timestamp_millis() - start.timestamp_millis();
-        let msg = format!("get {}({} ms).", self.resource.name(), duration);
+        let msg = format!(
+            "get {}({} ms{}).",
+            self.resource.name(),
+            duration,
+            stats.summary()
+        );
 
         ui_state.logs.info(&format!("{} get complete.", msg));
-        self.initial_request_count = 0;
+        for list_api_call in &mut self.list_api_calls {
+            list_api_call.initial_request_count = 0;
+        }
         self.table.add_get_yaml(yaml, resource_index);
 
         let section = self.create_section_and_get_detail(ui_state);
@@ -266,50 +396,160 @@ impl Scene {
         &mut self,
         ui_state: &mut UiState,
         start: DateTime<Local>,
+        target: Target,
         yaml: crate::service::ResourceList,
         next_token: Option<String>,
+        stats: CallStats,
     ) {
-        if let ListApiCall::Requesting { start: scene_start } = self.list_api_call {
-            if start != scene_start {
-                return;
-            }
-        } else {
+        let index = match self
+            .list_api_calls
+            .iter()
+            .position(|call| call.target == target)
+        {
+            Some(index) => index,
+            None => return,
+        };
+
+        if !self.is_requesting(index, start) {
             return;
         }
+        self.list_api_calls[index].abort_handle = None;
+
+        if let Some(cached_at) = stats.cached_at {
+            self.cached_at = Some(match self.cached_at {
+                Some(scene_cached_at) if scene_cached_at < cached_at => scene_cached_at,
+                _ => cached_at,
+            });
+        }
 
         let duration = Local::now().timestamp_millis() - start.timestamp_millis();
         let msg = format!(
-            "fetched {} {}({} ms).",
+            "fetched {} {}{}({} ms{}).",
             yaml.len(),
             self.resource.name(),
-            duration
+            match self.target_columns.values(&target).join(" ") {
+                values if values.is_empty() => values,
+                values => format!(" in {}", values),
+            },
+            duration,
+            stats.summary()
         );
 
-        self.list_api_call = match next_token {
+        let total = self.table.items.len() + yaml.len();
+        let fetch_all =
+            (self.base.opts.all || self.auto_select) && total < self.base.opts.max_items();
+        let list_api_call = &mut self.list_api_calls[index];
+        list_api_call.api_call = match next_token {
             Some(next_token) => {
-                ui_state.logs.info(&msg);
+                if fetch_all {
+                    ui_state.logs.info(&format!("{} {} in total.", msg, total));
+                } else if self.base.opts.all {
+                    ui_state.logs.info(&format!(
+                        "{} reached max items {}. hit \"A\" to fetch more.",
+                        msg,
+                        self.base.opts.max_items()
+                    ));
+                } else {
+                    ui_state.logs.info(&msg);
+                }
                 ListApiCall::StillHave { next_token }
             }
             None => {
                 ui_state.logs.info(&format!("{} fetch complete.", msg));
-                self.initial_request_count = 0;
+                list_api_call.initial_request_count = 0;
                 ListApiCall::Completed
             }
         };
 
-        self.table.add_resource_list(yaml, &self.search_text);
+        self.table
+            .add_resource_list(yaml, &target, &self.search_text);
 
-        if 0 < self.initial_request_count {
-            self.initial_request_count -= 1;
-            if 0 < self.initial_request_count {
-                self.call_list_api(ui_state);
+        if fetch_all {
+            self.call_target_list_api(index, ui_state);
+        } else if 0 < self.list_api_calls[index].initial_request_count {
+            self.list_api_calls[index].initial_request_count -= 1;
+            if 0 < self.list_api_calls[index].initial_request_count {
+                self.call_target_list_api(index, ui_state);
             }
         }
         self.get_initial_some(ui_state);
     }
 
+    /// Keep the next token of the failed request to retry it with "A".
+    fn handle_list_failure(&mut self, start: DateTime<Local>, target: Target, timed_out: bool) {
+        let index = match self
+            .list_api_calls
+            .iter()
+            .position(|call| call.target == target)
+        {
+            Some(index) => index,
+            None => return,
+        };
+
+        if !self.is_requesting(index, start) {
+            return;
+        }
+
+        let list_api_call = &mut self.list_api_calls[index];
+        list_api_call.abort_handle = None;
+        list_api_call.initial_request_count = 0;
+        if let ListApiCall::Requesting { next_token, .. } = &list_api_call.api_call {
+            list_api_call.api_call = if timed_out {
+                ListApiCall::TimedOut {
+                    next_token: next_token.clone(),
+                }
+            } else {
+                Self::before_request(next_token)
+            };
+        }
+    }
+
+    fn is_requesting(&self, index: usize, start: DateTime<Local>) -> bool {
+        match self.list_api_calls[index].api_call {
+            ListApiCall::Requesting {
+                start: scene_start, ..
+            } => start == scene_start,
+            _ => false,
+        }
+    }
+
+    fn before_request(next_token: &Option<String>) -> ListApiCall {
+        match next_token {
+            Some(next_token) => ListApiCall::StillHave {
+                next_token: next_token.clone(),
+            },
+            None => ListApiCall::None,
+        }
+    }
+
+    /// Abort list api calls in flight and drop queued get api calls.
+    fn cancel(&mut self, ui_state: &mut UiState) {
+        let mut cancelled = 0;
+        for list_api_call in &mut self.list_api_calls {
+            if let ListApiCall::Requesting { next_token, .. } = &list_api_call.api_call {
+                if let Some(abort_handle) = list_api_call.abort_handle.take() {
+                    abort_handle.abort();
+                }
+                list_api_call.api_call = Self::before_request(next_token);
+                list_api_call.initial_request_count = 0;
+                cancelled += 1;
+            }
+        }
+        self.cancel_get_api();
+
+        if cancelled > 0 {
+            ui_state.logs.info(&format!(
+                "cancelled {} list request(s). hit \"A\" to fetch again.",
+                cancelled
+            ));
+        } else {
+            ui_state.logs.info("no list request to cancel.");
+        }
+    }
+
     fn handle_keys(&mut self, key: Key, ui_state: &mut UiState) -> Result<Option<NextScene>> {
         use crate::ui::key_handler::*;
+        self.auto_select = false;
 
         if let Some(()) = text_input(key, &mut self.search_text) {
             self.table.filter(&self.search_text);
@@ -317,6 +557,7 @@ impl Scene {
         }
 
         if let Some(next) = common(key, &mut self.base, true) {
+            self.cancel_get_api();
             return Ok(Some(next));
         }
 
@@ -346,13 +587,15 @@ impl Scene {
             return Ok(None);
         }
 
+        if let Some(popup) = target_popup_open(key, self) {
+            self.overlay(UiScene::OptionPopup(popup));
+            return Ok(None);
+        }
+
         if let Some(popup) = section_popup_open(
             key,
             &self.base,
-            || {
-                self.table
-                    .selected_item_detail(&self.base.opts.region_name())
-            },
+            || self.table.selected_item_detail(),
             Box::new(UiScene::Resource(self.clone())),
         ) {
             self.overlay(UiScene::SectionPopup(popup));
@@ -364,6 +607,7 @@ impl Scene {
             Key::Tab => self.table.toggle_selected(),
             Key::Char('A') | Key::Ctrl('a') => self.call_list_api(ui_state),
             Key::Char('R') | Key::Ctrl('r') => self.reload(ui_state),
+            Key::Char('X') | Key::Ctrl('x') => self.cancel(ui_state),
             Key::Char('E') | Key::Ctrl('e') => self.export(ui_state)?,
             Key::Char('Y') | Key::Ctrl('y') => ui_state.toggle_viewer_mode(),
             Key::Char('G') | Key::Ctrl('g') if self.resource.get_api().is_some() => {
@@ -377,6 +621,7 @@ impl Scene {
     }
 
     fn select_resource(&mut self, ui_state: &mut UiState) -> NextScene {
+        self.cancel_get_api();
         return match &self.next_resource {
             Some(resource) => NextScene::Scene(select_next_scene(
                 Some(Box::new(UiScene::Resource(self.clone()))),
@@ -387,11 +632,15 @@ impl Scene {
                 self.base.tx.clone(),
             )),
             None => match self.table.selected_names_or_url(&self.base.opts) {
-                Some(names_or_url) => NextScene::Exit(Some(names_or_url)),
-                None => {
+                Ok(Some(names_or_url)) => NextScene::Exit(Ok(names_or_url)),
+                Ok(None) => {
                     ui_state.logs.info("no item");
                     NextScene::Same
                 }
+                Err(e) => {
+                    ui_state.logs.error(&e.to_string());
+                    NextScene::Same
+                }
             },
         };
     }
@@ -399,11 +648,9 @@ impl Scene {
     fn open_resource_url(&self) -> Result<()> {
         if self.resource.has_resource_url() {
             if let Some(item) = self.table.selected_item() {
-                let url = self.resource.console_url(
-                    &item.list_yaml,
-                    &item.get_yaml,
-                    &self.base.opts.region_name(),
-                );
+                let url =
+                    self.resource
+                        .console_url(&item.list_yaml, &item.get_yaml, &item.target.region);
                 open::that(url)?;
             }
         }
@@ -417,15 +664,17 @@ impl Scene {
     ) {
         let (status, (search, table, log), (info, viewer, help)) = layout::main::layout(f.size());
 
-        self.status.draw(&mut f, status, self.status(true));
+        let mut status_texts = self.status(true);
+        status_texts.append(&mut ui_state.identity_status(&self.base.opts));
+        self.status.draw(&mut f, status, status_texts);
         self.search.draw(&mut f, search, &self.search_text);
-        self.table.draw(&mut f, table, &self.list_api_call);
+        self.table.draw(&mut f, table, &self.list_api_call());
         self.log.draw(&mut f, log, ui_state.logs.to_text(2));
         self.viewer.draw(&mut f, &ui_state.viewer_mode, viewer);
         self.info.draw(
             &mut f,
             info,
-            &self.base.opts.region_name(),
+            &self.base.opts,
             self.viewer.scroll,
             self.viewer.line_len,
             true,
@@ -439,23 +688,35 @@ impl Scene {
     }
 
     fn reload(&mut self, ui_state: &mut UiState) {
-        self.list_api_call = ListApiCall::None;
+        self.cancel_get_api();
+        self.use_cache = false;
+        self.cached_at = None;
+        for list_api_call in &mut self.list_api_calls {
+            if let Some(abort_handle) = list_api_call.abort_handle.take() {
+                abort_handle.abort();
+            }
+            list_api_call.api_call = ListApiCall::None;
+        }
         self.table.clear();
         self.call_list_api(ui_state);
     }
 
     fn export(&mut self, ui_state: &mut UiState) -> Result<()> {
-        for (index, yaml) in self.table.selected_yamls().iter().enumerate() {
+        for (index, item) in self.table.selected_items().iter().enumerate() {
             let name = format!(
                 "{}-{}-{}",
                 self.resource.command_name(),
                 self.resource.resource_type_name(),
                 index + 1
             );
-            store_yaml(yaml, &name)?;
+            let header = ui_state
+                .identity(&item.target.profile)
+                .map(Identity::yaml_header)
+                .unwrap_or_default();
+            store_yaml_with_header(&item.list_yaml, &name, &header)?;
             ui_state.logs.info(&format!(
                 "{} stored in yaml file {}.",
-                self.resource.resource_name(yaml),
+                self.resource.resource_name(&item.list_yaml),
                 name
             ));
         }
@@ -479,46 +740,115 @@ impl Scene {
     }
 
     fn call_list_api(&mut self, ui_state: &mut UiState) {
-        let next_token = match &self.list_api_call {
-            ListApiCall::None => None,
-            ListApiCall::StillHave { next_token } => Some(next_token.to_owned()),
-            ListApiCall::Completed => {
-                ui_state
+        let mut called = false;
+        for index in 0..self.list_api_calls.len() {
+            called |= self.call_target_list_api(index, ui_state);
+        }
+
+        if !called {
+            match self.list_api_call() {
+                ListApiCall::Requesting { .. } => ui_state.logs.info("requesting"),
+                _ => ui_state
                     .logs
-                    .info(&format!("no more {}.", self.resource.name()));
-                return;
-            }
-            ListApiCall::Requesting { start: _start } => {
-                ui_state.logs.info("requesting");
-                return;
+                    .info(&format!("no more {}.", self.resource.name())),
             }
+        }
+    }
+
+    fn call_target_list_api(&mut self, index: usize, ui_state: &mut UiState) -> bool {
+        let next_token = match &self.list_api_calls[index].api_call {
+            ListApiCall::None => None,
+            ListApiCall::StillHave { next_token } => Some(next_token.to_owned()),
+            ListApiCall::TimedOut { next_token } => next_token.to_owned(),
+            ListApiCall::Completed | ListApiCall::Requesting { .. } => return false,
         };
 
         let resource = self.resource.clone();
         let mut tx = self.base.tx.clone();
         let parameter = self.parameter.clone();
-        let opts = self.base.opts.clone();
+        let target = self.list_api_calls[index].target.clone();
+        let opts = self.target_opts(&target);
+        let use_cache = self.use_cache;
         let start = Local::now();
+        let requested_token = next_token.clone();
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
 
-        tokio::spawn(async move {
-            match crate::api::list::call(&*resource, &parameter, &opts, next_token).await {
-                Ok((yaml, next_token)) => {
+        let call = async move {
+            match crate::api::list::call(&*resource, &parameter, &opts, next_token, use_cache).await
+            {
+                Ok((yaml, next_token, stats)) => {
                     let _ = tx
                         .send(Event::ListResponse {
                             start,
+                            target,
                             yaml,
                             next_token,
+                            stats,
                         })
                         .await;
                 }
                 Err(e) => {
-                    let _ = tx.send(Event::Log(Log::error(&format!("{:?}", e)))).await;
+                    let timed_out = matches!(e, crate::error::Error::TimeoutError(_));
+                    let _ = tx.send(Event::Log(Log::error(&e.to_string()))).await;
+                    let _ = tx
+                        .send(Event::ListFailure {
+                            start,
+                            target,
+                            timed_out,
+                        })
+                        .await;
                 }
             }
-        });
+        };
+        tokio::spawn(Abortable::new(call, abort_registration));
 
         ui_state.api_count_up();
-        self.list_api_call = ListApiCall::Requesting { start };
+        let list_api_call = &mut self.list_api_calls[index];
+        list_api_call.api_call = ListApiCall::Requesting {
+            start,
+            next_token: requested_token,
+        };
+        list_api_call.abort_handle = Some(abort_handle);
+        true
+    }
+
+    /// State of all targets for the table title, requesting while any target is requesting, then timed out.
+    fn list_api_call(&self) -> ListApiCall {
+        let calls = self.list_api_calls.iter().map(|call| &call.api_call);
+        if let Some(requesting) = calls
+            .clone()
+            .find(|call| matches!(call, ListApiCall::Requesting { .. }))
+        {
+            return requesting.clone();
+        }
+        if let Some(timed_out) = calls
+            .clone()
+            .find(|call| matches!(call, ListApiCall::TimedOut { .. }))
+        {
+            return timed_out.clone();
+        }
+        if let Some(still_have) = calls
+            .clone()
+            .find(|call| matches!(call, ListApiCall::StillHave { .. }))
+        {
+            return still_have.clone();
+        }
+        if calls
+            .clone()
+            .all(|call| matches!(call, ListApiCall::Completed))
+        {
+            return ListApiCall::Completed;
+        }
+        ListApiCall::None
+    }
+
+    /// Options for the requests of a target, unchanged when the scene lists only one target.
+    fn target_opts(&self, target: &Target) -> Opts {
+        if self.list_api_calls.len() > 1 {
+            self.base.opts.with_target(target)
+        } else {
+            self.base.opts.clone()
+        }
     }
 
     fn call_get_api(&mut self, item: &Item, ui_state: &mut UiState) {
@@ -526,25 +856,29 @@ impl Scene {
         let list_yaml = item.list_yaml.clone();
         let resource = self.resource.clone();
         let mut tx = self.base.tx.clone();
-        let opts = self.base.opts.clone();
+        let opts = self.target_opts(&item.target);
+        let use_cache = self.use_cache;
         let start = Local::now();
 
-        tokio::spawn(async move {
-            match crate::api::get::call(&*resource, &list_yaml, &opts).await {
-                Ok(yaml) => {
-                    let _ = tx
-                        .send(Event::GetResponse {
-                            start,
-                            yaml,
-                            resource_index,
-                        })
-                        .await;
-                }
-                Err(e) => {
-                    let _ = tx.send(Event::Log(Log::error(&format!("{:?}", e)))).await;
+        ui_state
+            .scheduler
+            .spawn(self.cancellation.token(), async move {
+                match crate::api::get::call(&*resource, &list_yaml, &opts, use_cache).await {
+                    Ok((yaml, stats)) => {
+                        let _ = tx
+                            .send(Event::GetResponse {
+                                start,
+                                yaml,
+                                resource_index,
+                                stats,
+                            })
+                            .await;
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Event::Log(Log::error(&e.to_string()))).await;
+                    }
                 }
-            }
-        });
+            });
 
         ui_state.api_count_up();
 
@@ -552,7 +886,39 @@ impl Scene {
         self.getting_item_index.push(item.index);
     }
 
+    fn cancel_get_api(&mut self) {
+        self.cancellation.cancel();
+        self.get_api_call.clear();
+        self.getting_item_index.clear();
+    }
+
+    /// The same resources of another region or profile, ESC goes back to this scene.
+    pub(in crate::ui) fn retarget(&self, opts: Opts, ui_state: &mut UiState) -> Scene {
+        ui_state.identify(&opts);
+        let mut previous = self.clone();
+        previous.cancel_get_api();
+        previous.target_label = Some(target_label(&self.base.opts));
+
+        let mut scene = new(
+            SceneBase::with_history(
+                opts,
+                self.base.tx.clone(),
+                Some(Box::new(UiScene::Resource(previous))),
+            ),
+            self.parameter.clone(),
+            self.resource.clone(),
+            self.next_resource.clone(),
+            ui_state,
+        );
+        scene.target_label = Some(target_label(&scene.base.opts));
+        scene
+    }
+
     pub(crate) fn overlay(&mut self, other: UiScene) {
         self.base.overlay = Some(Box::new(other));
     }
 }
+
+fn target_label(opts: &Opts) -> String {
+    format!("{} {}", opts.profile_name(), opts.region_label())
+}
@@ -1,3 +1,4 @@
+use crate::api::CallStats;
 use crate::log::Log;
 use crate::service::prelude::Yaml;
 use chrono::prelude::*;
@@ -7,13 +8,22 @@ use tokio::sync::mpsc;
 pub(crate) enum Event {
     ListResponse {
         start: DateTime<Local>,
+        target: crate::opts::Target,
         yaml: crate::service::ResourceList,
         next_token: Option<String>,
+        stats: CallStats,
+    },
+    /// List api call failed, the target can be fetched again with "A".
+    ListFailure {
+        start: DateTime<Local>,
+        target: crate::opts::Target,
+        timed_out: bool,
     },
     GetResponse {
         start: DateTime<Local>,
         yaml: Yaml,
         resource_index: usize,
+        stats: CallStats,
     },
     Log(Log),
 }
@@ -2,6 +2,8 @@ use super::TypedTerminal;
 use crate::error::Result;
 use tui::{backend::RustboxBackend, Terminal};
 pub(crate) mod event;
+pub(crate) mod prompt;
+pub(crate) mod scheduler;
 
 pub(crate) fn terminal() -> Result<TypedTerminal> {
     let backend = RustboxBackend::new()?;
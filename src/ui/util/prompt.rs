@@ -0,0 +1,44 @@
+use super::super::{layout, TypedTerminal};
+use crate::error::Result;
+use rustbox::keyboard::Key;
+use tui::widgets::{Block, Borders, Clear, Paragraph, Text};
+
+/// Popup to type the mfa token code in, None when ESC is hit.
+pub(crate) fn mfa_token(
+    terminal: &mut TypedTerminal,
+    serial: &str,
+    message: &str,
+) -> Result<Option<String>> {
+    let mut token = String::new();
+    loop {
+        terminal.draw(|mut f| {
+            let area = layout::popup::layout(60, 30, f.size());
+            let text = [
+                Text::raw(format!(" {}\n", serial)),
+                Text::raw(format!(" {}\n\n", message)),
+                Text::raw(format!(" > {}", token)),
+            ];
+            let widget = Paragraph::new(text.iter())
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" type mfa token code, ESC to quit "),
+                )
+                .wrap(true);
+            f.render_widget(Clear, area);
+            f.render_widget(widget, area);
+        })?;
+
+        if let Ok(rustbox::Event::KeyEvent(key)) = terminal.backend().rustbox().poll_event(false) {
+            match key {
+                Key::Enter if !token.is_empty() => return Ok(Some(token)),
+                Key::Esc | Key::Ctrl('c') => return Ok(None),
+                Key::Backspace => {
+                    token.pop();
+                }
+                Key::Char(c) if c.is_ascii_digit() => token.push(c),
+                _ => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Runs api calls with a limited number of requests in flight.
+#[derive(Clone)]
+pub(crate) struct Scheduler {
+    semaphore: Arc<Semaphore>,
+    queued: Arc<AtomicUsize>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+pub(crate) fn new(concurrency: usize) -> Scheduler {
+    Scheduler {
+        semaphore: Arc::new(Semaphore::new(concurrency)),
+        queued: Arc::new(AtomicUsize::new(0)),
+        in_flight: Arc::new(AtomicUsize::new(0)),
+    }
+}
+
+impl Scheduler {
+    pub(crate) fn spawn<F>(&self, token: CancelToken, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let semaphore = self.semaphore.clone();
+        let queued = self.queued.clone();
+        let in_flight = self.in_flight.clone();
+
+        queued.fetch_add(1, Ordering::SeqCst);
+        tokio::spawn(async move {
+            let _permit = semaphore.acquire().await;
+            queued.fetch_sub(1, Ordering::SeqCst);
+            if token.is_cancelled() {
+                return;
+            }
+
+            in_flight.fetch_add(1, Ordering::SeqCst);
+            future.await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+
+    pub(crate) fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+}
+
+/// Cancels queued requests of a scene when the scene is left or reloaded.
+#[derive(Clone)]
+pub(crate) struct Cancellation(Arc<AtomicUsize>);
+
+pub(crate) struct CancelToken {
+    generation: Arc<AtomicUsize>,
+    issued: usize,
+}
+
+impl Cancellation {
+    pub(crate) fn new() -> Self {
+        Self(Arc::new(AtomicUsize::new(0)))
+    }
+
+    pub(crate) fn token(&self) -> CancelToken {
+        CancelToken {
+            generation: self.0.clone(),
+            issued: self.0.load(Ordering::SeqCst),
+        }
+    }
+
+    pub(crate) fn cancel(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl CancelToken {
+    fn is_cancelled(&self) -> bool {
+        self.generation.load(Ordering::SeqCst) != self.issued
+    }
+}
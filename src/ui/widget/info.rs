@@ -1,4 +1,5 @@
 use crate::color;
+use crate::opts::Opts;
 use crate::ui::{UiState, ViewerMode};
 use tui::style::Style;
 use tui::{
@@ -21,7 +22,7 @@ impl Info {
         &mut self,
         f: &mut Frame<B>,
         area: Rect,
-        region: &str,
+        opts: &Opts,
         view_scroll: u16,
         line_len: u16,
         show_viewer: bool,
@@ -37,12 +38,22 @@ impl Info {
             ViewerMode::Yaml => (inactive, active),
         };
 
+        let (queued, in_flight) = (ui_state.scheduler.queued(), ui_state.scheduler.in_flight());
+        let queue = if queued + in_flight > 0 {
+            format!("queue: {} / in flight: {} / ", queued, in_flight)
+        } else {
+            "".to_string()
+        };
+
         let mut text = vec![
             Text::raw(format!(
-                "request: {} / region: {}\n",
-                ui_state.api_count, region,
+                "{}request: {} / profile: {} / region: {}\n",
+                queue,
+                ui_state.api_count,
+                opts.profile_label(),
+                opts.region_label(),
             )),
-            Text::raw("\n"),
+            Text::raw(format!("{}\n", account(opts, ui_state))),
             Text::raw(format!("({}/{}) ", view_scroll, line_len)),
         ];
 
@@ -61,3 +72,24 @@ impl Info {
         f.render_widget(guide, area);
     }
 }
+
+/// Account and arn of the single profile, or the accounts of the profiles.
+fn account(opts: &Opts, ui_state: &UiState) -> String {
+    match opts.profiles().as_slice() {
+        [profile] => match ui_state.identity(profile) {
+            Some(identity) => format!("account: {} / {}", identity.account_label(), identity.arn),
+            None => "account: -".to_string(),
+        },
+        profiles => format!(
+            "accounts: {}",
+            profiles
+                .iter()
+                .map(|profile| match ui_state.identity(profile) {
+                    Some(identity) => identity.account_label(),
+                    None => "-".to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
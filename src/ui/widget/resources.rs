@@ -1,8 +1,10 @@
-use crate::opts::Opts;
+use crate::error::Result;
+use crate::opts::{Opts, Target, TargetColumns};
 use crate::service::AwsResource;
 use crate::show;
 use crate::ui::scene::resources::ListApiCall;
 use crate::ui::widget::util::table;
+use crate::yaml_path::apply_list_or_get;
 use tui::{
     backend::Backend,
     layout::{Constraint, Rect},
@@ -16,6 +18,7 @@ use yaml_rust::Yaml;
 #[derive(Debug, Clone)]
 pub(crate) struct Item {
     pub(crate) index: usize,
+    pub(crate) target: Target,
     row: Vec<String>,
     match_string: String,
     pub(crate) list_yaml: Yaml,
@@ -37,15 +40,17 @@ pub(crate) struct Resources {
     pub(crate) selected_indexes: Vec<usize>,
     column_max_lengths: Vec<usize>,
     resource: Box<dyn AwsResource>,
+    target_columns: TargetColumns,
 }
 
-pub(crate) fn new(resource: Box<dyn AwsResource>) -> Resources {
+pub(crate) fn new(resource: Box<dyn AwsResource>, target_columns: TargetColumns) -> Resources {
     let items = vec![];
 
     let mut s = Resources {
         state: TableState::default(),
         items,
         resource,
+        target_columns,
         filtered_indexes: vec![],
         last_height: 0,
         selected_indexes: vec![],
@@ -71,13 +76,16 @@ impl Resources {
     pub(crate) fn add_resource_list(
         &mut self,
         yaml: crate::service::ResourceList,
+        target: &Target,
         search_text: &str,
     ) {
         for (line, yaml) in yaml {
+            let row = self.row_with_target(line, target);
             self.items.push(Item {
                 index: self.items.len(),
-                match_string: line.join(" "),
-                row: line,
+                target: target.clone(),
+                match_string: row.join(" "),
+                row,
                 list_yaml: yaml,
                 get_yaml: None,
             });
@@ -87,27 +95,36 @@ impl Resources {
     }
 
     pub(crate) fn add_get_yaml(&mut self, yaml: Yaml, resource_index: usize) {
-        let mut item = &mut self.items[resource_index];
-        item.get_yaml = Some(yaml);
-        item.row = self.resource.line(&item.list_yaml, &item.get_yaml);
-        item.match_string = item.row.join(" ");
+        let item = &self.items[resource_index];
+        let get_yaml = Some(yaml);
+        let row =
+            self.row_with_target(self.resource.line(&item.list_yaml, &get_yaml), &item.target);
+
+        let item = &mut self.items[resource_index];
+        item.get_yaml = get_yaml;
+        item.match_string = row.join(" ");
+        item.row = row;
         self.calc_column_max_lengths();
     }
 
+    fn row_with_target(&self, mut line: Vec<String>, target: &Target) -> Vec<String> {
+        line.append(&mut self.target_columns.values(target));
+        line
+    }
+
+    fn header(&self) -> Vec<&'static str> {
+        let mut header = self.resource.info().header.clone();
+        header.append(&mut self.target_columns.header());
+        header
+    }
+
     pub(crate) fn calc_column_max_lengths(&mut self) {
         let mut row_lengths: Vec<Vec<usize>> = self
             .items
             .iter()
             .map(|item| item.row.iter().map(|s| s.len()).collect())
             .collect();
-        row_lengths.push(
-            self.resource
-                .info()
-                .header
-                .iter()
-                .map(|s| s.len())
-                .collect(),
-        );
+        row_lengths.push(self.header().iter().map(|s| s.len()).collect());
         self.column_max_lengths = super::column_max_list(&row_lengths);
     }
 
@@ -132,36 +149,49 @@ impl Resources {
         None
     }
 
-    pub(crate) fn selected_names_or_url(&mut self, opts: &Opts) -> Option<String> {
-        let selected_items = self
-            .selected_items()
-            .iter()
-            .map(|item| match opts.output_type() {
-                crate::opts::OutputType::ConsoleURL => {
-                    self.resource
-                        .console_url(&item.list_yaml, &item.get_yaml, &opts.region_name())
-                }
-                crate::opts::OutputType::ResourceIdentifier => {
-                    self.resource.resource_name(&item.list_yaml)
-                }
-            })
-            .collect::<Vec<String>>();
-
-        if selected_items.len() > 0 {
-            Some(selected_items.join(&opts.delimiter()))
-        } else {
-            None
+    pub(crate) fn selected_names_or_url(&mut self, opts: &Opts) -> Result<Option<String>> {
+        let output_type = opts.output_type();
+        let selected_items = self.selected_items();
+        if selected_items.is_empty() {
+            return Ok(None);
         }
-    }
 
-    pub(crate) fn selected_yamls(&mut self) -> Vec<Yaml> {
-        self.selected_items()
-            .iter()
-            .map(|item| item.list_yaml.clone())
-            .collect()
+        let lines = match &output_type {
+            crate::opts::OutputType::Format(template) => template.render(
+                &selected_items
+                    .iter()
+                    .map(|item| (&item.list_yaml, &item.get_yaml))
+                    .collect::<Vec<_>>(),
+            )?,
+            _ => selected_items
+                .iter()
+                .map(|item| match &output_type {
+                    crate::opts::OutputType::ConsoleURL => self.resource.console_url(
+                        &item.list_yaml,
+                        &item.get_yaml,
+                        &item.target.region,
+                    ),
+                    crate::opts::OutputType::Query(paths) => paths
+                        .iter()
+                        .map(|path| {
+                            let value = apply_list_or_get(path, &item.list_yaml, &item.get_yaml);
+                            crate::yaml_path::text(&value).replace(&['\t', '\n'][..], " ")
+                        })
+                        .collect::<Vec<String>>()
+                        .join("\t"),
+                    _ => self.resource.resource_name(&item.list_yaml),
+                })
+                .collect(),
+        };
+
+        let delimiter = match output_type {
+            crate::opts::OutputType::Query(_) => "\n".to_string(),
+            _ => opts.delimiter(),
+        };
+        Ok(Some(lines.join(&delimiter)))
     }
 
-    fn selected_items(&mut self) -> Vec<Item> {
+    pub(crate) fn selected_items(&mut self) -> Vec<Item> {
         if let Some(index) = self.state.selected() {
             self.selected_indexes.push(self.filtered_indexes[index]);
         }
@@ -194,7 +224,7 @@ impl Resources {
     {
         let filtered_items = table::filtered_items(&self.items, &self.filtered_indexes);
         self.last_height = area.height;
-        let header = self.resource.info().header.clone();
+        let header = self.header();
         let column_width = self.column_width();
         let title = self.title(api_call);
         let selected_indexes = self.selected_indexes.clone();
@@ -240,6 +270,7 @@ impl Resources {
             ListApiCall::None => "-",
             ListApiCall::StillHave { .. } => "remaining",
             ListApiCall::Requesting { .. } => "requesting",
+            ListApiCall::TimedOut { .. } => "timed out, hit A to retry",
             ListApiCall::Completed => "fetched all",
         };
 
@@ -262,11 +293,12 @@ impl Resources {
         )
     }
 
-    pub(crate) fn selected_item_detail(&self, region: &str) -> show::Section {
+    pub(crate) fn selected_item_detail(&self) -> show::Section {
         match self.selected_item() {
-            Some(item) => self
-                .resource
-                .detail(&item.list_yaml, &item.get_yaml, region),
+            Some(item) => {
+                self.resource
+                    .detail(&item.list_yaml, &item.get_yaml, &item.target.region)
+            }
             None => show::Section::new_without_yaml(),
         }
     }
@@ -1,9 +1,330 @@
+use crate::error::Error::*;
+use crate::error::Result;
+use serde::{Serialize, Serializer};
 use yaml_rust::Yaml;
 
-pub(crate) fn apply_path<'a>(yaml: &'a Yaml, path: &[&str]) -> &'a Yaml {
-    if path.len() == 0 {
-        yaml
-    } else {
-        apply_path(&yaml[path[0]], &path[1..])
+/// Path to values in yaml like `a.b[0].c`, `tags[*].value` or `tag_set[?key=='Name'].value`.
+/// The empty path is the yaml itself.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Path {
+    expression: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    /// Negative index counts from the end.
+    Index(i64),
+    Wildcard,
+    Filter {
+        path: Path,
+        equal: bool,
+        value: String,
+    },
+}
+
+impl Path {
+    /// Path written in the service definitions, a malformed one is a bug.
+    pub(crate) fn new(expression: &str) -> Path {
+        Path::parse(expression).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub(crate) fn parse(expression: &str) -> Result<Path> {
+        let invalid =
+            |reason: &str| ArgumentError(format!("invalid path \"{}\": {}", expression, reason));
+        let mut segments = vec![];
+        let mut key = String::new();
+        let mut chars = expression.chars();
+        let mut after_bracket = false;
+
+        while let Some(c) = chars.next() {
+            match c {
+                '.' if key.is_empty() && !after_bracket => return Err(invalid("empty key")),
+                '.' => {
+                    if !key.is_empty() {
+                        segments.push(Segment::Key(std::mem::take(&mut key)));
+                    }
+                    after_bracket = false;
+                }
+                '[' => {
+                    if !key.is_empty() {
+                        segments.push(Segment::Key(std::mem::take(&mut key)));
+                    }
+                    let mut bracket = String::new();
+                    let mut quote = None;
+                    loop {
+                        match (chars.next(), quote) {
+                            (None, _) => return Err(invalid("no closing ]")),
+                            (Some(']'), None) => break,
+                            (Some(c), None) if c == '\'' || c == '"' => {
+                                quote = Some(c);
+                                bracket.push(c);
+                            }
+                            (Some(c), Some(q)) if c == q => {
+                                quote = None;
+                                bracket.push(c);
+                            }
+                            (Some(c), _) => bracket.push(c),
+                        }
+                    }
+                    segments.push(
+                        Segment::bracket(bracket.trim()).ok_or_else(|| {
+                            invalid("use [0], [*] or [?key=='value'] in brackets")
+                        })?,
+                    );
+                    after_bracket = true;
+                }
+                ']' => return Err(invalid("no opening [")),
+                _ if after_bracket => return Err(invalid("put . after ]")),
+                c => key.push(c),
+            }
+        }
+
+        if !key.is_empty() {
+            segments.push(Segment::Key(key));
+        } else if expression.ends_with('.') {
+            return Err(invalid("empty key"));
+        }
+        Ok(Path {
+            expression: expression.to_string(),
+            segments,
+        })
+    }
+
+    /// Values at the path, wildcards and filters may select many.
+    pub(crate) fn select<'a>(&self, yaml: &'a Yaml) -> Vec<&'a Yaml> {
+        let mut values = vec![];
+        select(&self.segments, yaml, &mut values);
+        values
+    }
+
+    /// Value at the path, an array of the values when the path has wildcards or filters.
+    pub(crate) fn apply(&self, yaml: &Yaml) -> Yaml {
+        let values = self.select(yaml);
+        if self.is_projection() {
+            Yaml::Array(values.into_iter().cloned().collect())
+        } else {
+            values.first().cloned().cloned().unwrap_or(Yaml::BadValue)
+        }
+    }
+
+    fn is_projection(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Wildcard | Segment::Filter { .. }))
+    }
+}
+
+impl Serialize for Path {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.expression)
+    }
+}
+
+impl Segment {
+    fn bracket(expression: &str) -> Option<Segment> {
+        if expression == "*" {
+            return Some(Segment::Wildcard);
+        }
+        if let Ok(index) = expression.parse::<i64>() {
+            return Some(Segment::Index(index));
+        }
+
+        let filter = expression.strip_prefix('?')?;
+        let (path, value, equal) = match (filter.find("=="), filter.find("!=")) {
+            (Some(i), _) => (&filter[..i], &filter[i + 2..], true),
+            (None, Some(i)) => (&filter[..i], &filter[i + 2..], false),
+            _ => return None,
+        };
+        let value = value.trim();
+        let value = ['\'', '"', '`']
+            .iter()
+            .find_map(|q| value.strip_prefix(*q)?.strip_suffix(*q))
+            .unwrap_or(value);
+
+        Some(Segment::Filter {
+            path: Path::parse(path.trim()).ok()?,
+            equal,
+            value: value.to_string(),
+        })
+    }
+}
+
+fn select<'a>(segments: &[Segment], yaml: &'a Yaml, values: &mut Vec<&'a Yaml>) {
+    let (segment, rest) = match segments.split_first() {
+        Some(first) => first,
+        None => {
+            if !yaml.is_badvalue() {
+                values.push(yaml);
+            }
+            return;
+        }
+    };
+
+    match (segment, yaml) {
+        (Segment::Key(key), _) => select(rest, &yaml[key.as_str()], values),
+        (Segment::Index(index), Yaml::Array(items)) => {
+            let index = if *index < 0 {
+                items.len() as i64 + index
+            } else {
+                *index
+            };
+            if index >= 0 && (index as usize) < items.len() {
+                select(rest, &items[index as usize], values);
+            }
+        }
+        (Segment::Wildcard, Yaml::Array(items)) => {
+            for item in items {
+                select(rest, item, values);
+            }
+        }
+        (Segment::Wildcard, Yaml::Hash(hash)) => {
+            for item in hash.values() {
+                select(rest, item, values);
+            }
+        }
+        (Segment::Filter { path, equal, value }, Yaml::Array(items)) => {
+            for item in items {
+                if (crate::show::raw(&path.apply(item)) == *value) == *equal {
+                    select(rest, item, values);
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Value at the path in the list yaml, or in the get yaml if the list yaml doesn't have it.
+pub(crate) fn apply_list_or_get(path: &Path, list: &Yaml, get: &Option<Yaml>) -> Yaml {
+    match (path.apply(list), get) {
+        (Yaml::BadValue, Some(get)) => path.apply(get),
+        (Yaml::Array(values), Some(get)) if values.is_empty() => path.apply(get),
+        (value, _) => value,
+    }
+}
+
+/// Text of the value to print, arrays are separated by spaces and hashes are json.
+pub(crate) fn text(yaml: &Yaml) -> String {
+    match yaml {
+        Yaml::Real(real) => real.clone(),
+        Yaml::Array(items) => items.iter().map(text).collect::<Vec<_>>().join(" "),
+        Yaml::Hash(_) => crate::output::to_json(yaml).to_string(),
+        Yaml::Null | Yaml::BadValue => "".to_string(),
+        _ => crate::show::raw(yaml),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yaml_rust::YamlLoader;
+
+    fn yaml() -> Yaml {
+        YamlLoader::load_from_str(
+            r#"
+instance_id: i-1
+placement:
+  availability_zone: us-east-1a
+tag_set:
+  - key: Name
+    value: web
+  - key: env
+    value: prod
+  - key: "a]b"
+    value: bracket
+"#,
+        )
+        .unwrap()
+        .remove(0)
+    }
+
+    fn apply(expression: &str) -> Yaml {
+        Path::parse(expression).unwrap().apply(&yaml())
+    }
+
+    fn strings(values: &[&str]) -> Yaml {
+        Yaml::Array(values.iter().map(|v| Yaml::String(v.to_string())).collect())
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(apply("instance_id"), Yaml::String("i-1".to_string()));
+        assert_eq!(
+            apply("placement.availability_zone"),
+            Yaml::String("us-east-1a".to_string())
+        );
+        assert_eq!(apply("placement.nothing"), Yaml::BadValue);
+        assert_eq!(apply(""), yaml());
+    }
+
+    #[test]
+    fn indexes() {
+        assert_eq!(apply("tag_set[0].value"), Yaml::String("web".to_string()));
+        assert_eq!(
+            apply("tag_set[-1].value"),
+            Yaml::String("bracket".to_string())
+        );
+        assert_eq!(apply("tag_set[ 1 ].key"), Yaml::String("env".to_string()));
+        assert_eq!(apply("tag_set[3].value"), Yaml::BadValue);
+        assert_eq!(apply("tag_set[-4].value"), Yaml::BadValue);
+        assert_eq!(apply("instance_id[0]"), Yaml::BadValue);
+    }
+
+    #[test]
+    fn wildcards() {
+        assert_eq!(
+            apply("tag_set[*].value"),
+            strings(&["web", "prod", "bracket"])
+        );
+        assert_eq!(apply("placement[*]"), strings(&["us-east-1a"]));
+        assert_eq!(apply("nothing[*]"), strings(&[]));
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(apply("tag_set[?key=='Name'].value"), strings(&["web"]));
+        assert_eq!(apply("tag_set[?key == \"env\"].value"), strings(&["prod"]));
+        assert_eq!(apply("tag_set[?key==`Name`].value"), strings(&["web"]));
+        assert_eq!(
+            apply("tag_set[?key!='Name'].value"),
+            strings(&["prod", "bracket"])
+        );
+        assert_eq!(apply("tag_set[?key=='none'].value"), strings(&[]));
+    }
+
+    #[test]
+    fn quoted_brackets() {
+        assert_eq!(apply("tag_set[?key=='a]b'].value"), strings(&["bracket"]));
+        assert_eq!(apply("tag_set[?key==\"a]b\"].value"), strings(&["bracket"]));
+    }
+
+    #[test]
+    fn malformed() {
+        for expression in &[
+            ".a",
+            "a.",
+            "a..b",
+            "a[0",
+            "a]",
+            "a[x]",
+            "a[?key]",
+            "a[0]b",
+            "a[?key=='b'",
+        ] {
+            assert!(Path::parse(expression).is_err(), "{}", expression);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid path")]
+    fn malformed_definition() {
+        Path::new("a[");
+    }
+
+    #[test]
+    fn text_of_values() {
+        assert_eq!(text(&apply("tag_set[*].key")), "Name env a]b");
+        assert_eq!(text(&apply("nothing")), "");
     }
 }